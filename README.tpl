@@ -17,6 +17,14 @@
 [`serde::Serialize`]: https://docs.rs/serde/latest/serde/ser/trait.Serialize.html
 [`serde::Deserialize`]: https://docs.rs/serde/latest/serde/de/trait.Deserialize.html
 [`zeroize::Zeroize`]: https://docs.rs/zeroize/latest/zeroize/trait.Zeroize.html
+[`enable_duplicate_tracking`]: https://docs.rs/nid/latest/nid/fn.enable_duplicate_tracking.html
+[`Nanoid::from_name`]: https://docs.rs/nid/latest/nid/struct.Nanoid.html#method.from_name
+[`Nanoid::from_key`]: https://docs.rs/nid/latest/nid/struct.Nanoid.html#method.from_key
+[`PseudonymKeyring`]: https://docs.rs/nid/latest/nid/struct.PseudonymKeyring.html
+[`IdCipher`]: https://docs.rs/nid/latest/nid/struct.IdCipher.html
+[`uuid::Uuid`]: https://docs.rs/uuid/latest/uuid/struct.Uuid.html
+[`Nanoid::new_uuid_v4`]: https://docs.rs/nid/latest/nid/struct.Nanoid.html#method.new_uuid_v4
+[`Nanoid::new_uuid_v7`]: https://docs.rs/nid/latest/nid/struct.Nanoid.html#method.new_uuid_v7
 
 ## License
 
@@ -0,0 +1,74 @@
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use nid::{
+    Nanoid,
+    alphabet::{
+        Alphabet, Base16Alphabet, Base32Alphabet, Base58Alphabet, Base62Alphabet, Base64UrlAlphabet,
+    },
+};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// A random number generator that counts how many times it is called.
+struct CountingRng<R> {
+    rng: R,
+    calls: usize,
+}
+
+impl<R: Rng> rand::TryRng for CountingRng<R> {
+    type Error = std::convert::Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        self.calls += 1;
+        Ok(self.rng.next_u32())
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        self.calls += 1;
+        Ok(self.rng.next_u64())
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        self.calls += 1;
+        self.rng.fill_bytes(dst);
+        Ok(())
+    }
+}
+
+fn bench_alphabet<A: Alphabet>(c: &mut Criterion, name: &str) {
+    // Print the number of RNG calls per ID, which is independent of the machine.
+    let mut rng = CountingRng {
+        rng: StdRng::seed_from_u64(0),
+        calls: 0,
+    };
+    for _ in 0..1000 {
+        black_box(Nanoid::<21, A>::new_with(&mut rng));
+    }
+    println!("{name}: {:.2} RNG calls per ID", rng.calls as f64 / 1000.0);
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("new", |b| b.iter(Nanoid::<21, A>::new));
+    group.bench_function("new_with", |b| {
+        let mut rng = StdRng::seed_from_u64(0);
+        b.iter(|| Nanoid::<21, A>::new_with(&mut rng));
+    });
+    group.bench_function("new_vec_with", |b| {
+        let mut rng = StdRng::seed_from_u64(0);
+        b.iter(|| Nanoid::<21, A>::new_vec_with(1000, &mut rng));
+    });
+    group.finish();
+}
+
+fn bench_generate(c: &mut Criterion) {
+    // Power-of-two alphabets, which use the bit-extraction path.
+    bench_alphabet::<Base64UrlAlphabet>(c, "Base64UrlAlphabet");
+    bench_alphabet::<Base32Alphabet>(c, "Base32Alphabet");
+    bench_alphabet::<Base16Alphabet>(c, "Base16Alphabet");
+
+    // Other alphabets, which use unbiased sampling.
+    bench_alphabet::<Base62Alphabet>(c, "Base62Alphabet");
+    bench_alphabet::<Base58Alphabet>(c, "Base58Alphabet");
+}
+
+criterion_group!(benches, bench_generate);
+criterion_main!(benches);
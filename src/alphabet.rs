@@ -1,12 +1,15 @@
 //! This module defines the [`Alphabet`] trait and provides implementations for the most common alphabets used in Nano ID.
 //!
-//! An alphabet is a set of symbols that can be used in Nano ID. In this crate, only ASCII characters can be used as symbols.
+//! An alphabet is a set of symbols that can be used in Nano ID. In [`Nanoid`](crate::Nanoid), only ASCII characters can be used as symbols.
+//! For alphabets of arbitrary Unicode characters, use [`UnicodeAlphabet`] and [`UnicodeNanoid`](crate::UnicodeNanoid) instead.
 //!
 //! The default alphabet used in Nano ID is [`Base64UrlAlphabet`], which contains `A-Za-z0-9_-` symbols.
 //!
 //! # Implementing a custom alphabet
 //!
 //! To implement a custom alphabet, you need to create a new type that implements the [`Alphabet`] trait.
+//! The [`alphabet!`] macro defines one from character ranges, and [`Union`], [`Difference`] and [`Intersection`]
+//! combine existing alphabets.
 //!
 //! # Examples
 //!
@@ -30,7 +33,11 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
-// NOTE: Currently symbols are represented as [`u8`] values. We may change this to [`std::ascii::Char`] when it becomes stable.
+use std::marker::PhantomData;
+
+use self::__private::AlphabetBuilder;
+
+// NOTE: Currently symbols of `Alphabet` are represented as [`u8`] values. We may change this to [`std::ascii::Char`] when it becomes stable.
 
 /// A set of symbols that can be used in Nano ID. In this crate, only ASCII characters can be used as symbols.
 ///
@@ -92,9 +99,64 @@
 ///
 /// let id: Nanoid<21, CustomAlphabet> = Nanoid::new(); // Compilation error: found non-ascii symbol in alphabet
 /// ```
+///
+/// # Aliases
+///
+/// An alphabet can also accept aliases of its symbols when parsing, which is useful for human-entered codes.
+/// Aliases are replaced with their canonical symbols, so equal Nano IDs always compare and hash equal after parsing.
+///
+/// ```rust
+/// use nid::{alphabet::Alphabet, Nanoid};
+///
+/// struct CodeAlphabet;
+///
+/// impl Alphabet for CodeAlphabet {
+///     const SYMBOL_LIST: &'static [u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+///     const ALIASES: &'static [(u8, u8)] = &[(b'o', b'0'), (b'O', b'0'), (b'l', b'1'), (b'I', b'1')];
+/// }
+///
+/// let id: Nanoid<6, CodeAlphabet> = "lO2ABo".parse()?;
+/// assert_eq!(id.as_str(), "102AB0");
+/// assert_eq!(id, "102AB0".parse()?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// An alias must be an ASCII character that is not in the alphabet, and it must be mapped to a symbol in the alphabet.
+/// A compilation error occurs if any of these requirements is violated.
+///
+/// ```compile_fail
+/// use nid::{alphabet::Alphabet, Nanoid};
+///
+/// struct CustomAlphabet;
+///
+/// impl Alphabet for CustomAlphabet {
+///     const SYMBOL_LIST: &'static [u8] = b"0123456789";
+///     const ALIASES: &'static [(u8, u8)] = &[(b'1', b'0')];
+/// }
+///
+/// let id: Nanoid<21, CustomAlphabet> = "000000000000000000000".parse().unwrap(); // Compilation error: alias must not be a symbol in alphabet
+/// ```
+///
+/// ```compile_fail
+/// use nid::{alphabet::Alphabet, Nanoid};
+///
+/// struct CustomAlphabet;
+///
+/// impl Alphabet for CustomAlphabet {
+///     const SYMBOL_LIST: &'static [u8] = b"0123456789";
+///     const ALIASES: &'static [(u8, u8)] = &[(b'o', b'O')];
+/// }
+///
+/// let id: Nanoid<21, CustomAlphabet> = "000000000000000000000".parse().unwrap(); // Compilation error: alias must be mapped to a symbol in alphabet
+/// ```
 pub trait Alphabet {
     /// The symbols that can be used in Nano ID. Symbols are represented as [`u8`] values.
     const SYMBOL_LIST: &'static [u8];
+
+    /// The aliases accepted when parsing a Nano ID, as pairs of an alias and its canonical symbol.
+    ///
+    /// Defaults to no aliases.
+    const ALIASES: &'static [(u8, u8)] = &[];
 }
 
 /// An extension trait for [`Alphabet`] that provides additional constants.
@@ -109,8 +171,35 @@ pub(crate) trait AlphabetExt {
     ///
     /// If [`Alphabet::SYMBOL_LIST`] contains non-ASCII characters, reading this constant will result in a compilation error.
     const VALID_SYMBOL_MAP: [bool; 128];
+
+    /// A map from a symbol to its position in [`AlphabetExt::VALID_SYMBOL_LIST`].
+    ///
+    /// When the value of a Nano ID is calculated, such as in [`Nanoid::to_u128`](crate::Nanoid::to_u128),
+    /// the digit value of a symbol is its position in the alphabet.
+    /// The value for a symbol that is not in the alphabet is unspecified.
+    const SYMBOL_INDEX_MAP: [u8; 128];
+
+    /// The symbols sorted in ascending byte order. Only the first `VALID_SYMBOL_LIST.len()` elements are meaningful.
+    ///
+    /// When a Nano ID must sort in byte order, such as in time-sortable Nano IDs, the digit value of a symbol is
+    /// its position in this list. This way, the numeric order of the digits is the same as the byte order.
+    const DIGIT_LIST: [u8; 128];
+
+    /// A map from a symbol to its digit value, i.e. its position in [`AlphabetExt::DIGIT_LIST`].
+    ///
+    /// The value for a symbol that is not in the alphabet is unspecified.
+    const DIGIT_MAP: [u8; 128];
+
+    /// A map from a character to its canonical symbol, which is the symbol itself or the symbol an alias is mapped to.
+    ///
+    /// The value for a character that is neither a symbol nor an alias is [`INVALID_SYMBOL`], which is not ASCII.
+    /// If [`Alphabet::ALIASES`] is invalid, reading this constant will result in a compilation error.
+    const NORMALIZE_MAP: [u8; 128];
 }
 
+/// The value of [`AlphabetExt::NORMALIZE_MAP`] for a character that is not accepted by the alphabet.
+pub(crate) const INVALID_SYMBOL: u8 = u8::MAX;
+
 impl<A: Alphabet> AlphabetExt for A {
     const VALID_SYMBOL_LIST: &'static [u8] = {
         assert!(!A::SYMBOL_LIST.is_empty(), "alphabet must not be empty");
@@ -128,6 +217,241 @@ impl<A: Alphabet> AlphabetExt for A {
         }
         symbols_map
     };
+
+    const SYMBOL_INDEX_MAP: [u8; 128] = {
+        let mut index_map = [0; 128];
+        let mut i = 0;
+        while i < A::VALID_SYMBOL_LIST.len() {
+            index_map[A::VALID_SYMBOL_LIST[i] as usize] = i as u8;
+            i += 1;
+        }
+        index_map
+    };
+
+    const DIGIT_LIST: [u8; 128] = {
+        let mut digit_list = [0; 128];
+        let mut len = 0;
+        let mut symbol = 0;
+        while symbol < A::VALID_SYMBOL_MAP.len() {
+            if A::VALID_SYMBOL_MAP[symbol] {
+                digit_list[len] = symbol as u8;
+                len += 1;
+            }
+            symbol += 1;
+        }
+        digit_list
+    };
+
+    const DIGIT_MAP: [u8; 128] = {
+        let mut digit_map = [0; 128];
+        let mut i = 0;
+        while i < A::VALID_SYMBOL_LIST.len() {
+            digit_map[A::DIGIT_LIST[i] as usize] = i as u8;
+            i += 1;
+        }
+        digit_map
+    };
+
+    const NORMALIZE_MAP: [u8; 128] = {
+        let mut normalize_map = [INVALID_SYMBOL; 128];
+        let mut i = 0;
+        while i < A::VALID_SYMBOL_LIST.len() {
+            normalize_map[A::VALID_SYMBOL_LIST[i] as usize] = A::VALID_SYMBOL_LIST[i];
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < A::ALIASES.len() {
+            let (alias, symbol) = A::ALIASES[i];
+            assert!(alias.is_ascii(), "found non-ascii alias in alphabet");
+            assert!(
+                !A::VALID_SYMBOL_MAP[alias as usize],
+                "alias must not be a symbol in alphabet"
+            );
+            assert!(
+                normalize_map[alias as usize] == INVALID_SYMBOL,
+                "found duplicate alias in alphabet"
+            );
+            assert!(
+                symbol.is_ascii() && A::VALID_SYMBOL_MAP[symbol as usize],
+                "alias must be mapped to a symbol in alphabet"
+            );
+            normalize_map[alias as usize] = symbol;
+            i += 1;
+        }
+        normalize_map
+    };
+}
+
+/// Aliases that map the lowercase letters `a-z` to the uppercase letters `A-Z`.
+const LOWERCASE_ALIASES: [(u8, u8); 26] = {
+    let mut aliases = [(0, 0); 26];
+    let mut i = 0;
+    while i < aliases.len() {
+        aliases[i] = (b'a' + i as u8, b'A' + i as u8);
+        i += 1;
+    }
+    aliases
+};
+
+/// Aliases of Crockford's Base32: the lowercase letters, and `I`, `L` and `O` read as `1`, `1` and `0`.
+const BASE32_CROCKFORD_ALIASES: [(u8, u8); 28] = {
+    let mut aliases = [(0, 0); 28];
+    let mut len = 0;
+    let mut i = 0;
+    while i < 26 {
+        let (lowercase, uppercase) = (b'a' + i, b'A' + i);
+        let symbol = match uppercase {
+            b'I' | b'L' => b'1',
+            b'O' => b'0',
+            _ => uppercase,
+        };
+        // `U` is excluded from the alphabet and has no alias.
+        if uppercase != b'U' {
+            aliases[len] = (lowercase, symbol);
+            len += 1;
+            if symbol != uppercase {
+                aliases[len] = (uppercase, symbol);
+                len += 1;
+            }
+        }
+        i += 1;
+    }
+    assert!(len == aliases.len());
+    aliases
+};
+
+/// A set of arbitrary Unicode characters that can be used in [`UnicodeNanoid`](crate::UnicodeNanoid).
+///
+/// Each symbol is a single [`char`], so emoji or graphemes that consist of multiple code points can't be used as symbols.
+///
+/// # Implementing a custom alphabet
+///
+/// ```rust
+/// use nid::{alphabet::UnicodeAlphabet, UnicodeNanoid};
+///
+/// struct CyrillicAlphabet;
+///
+/// impl UnicodeAlphabet for CyrillicAlphabet {
+///     const SYMBOL_LIST: &'static [char] = &['а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з', 'и', 'к', 'л', 'м'];
+/// }
+///
+/// let id: UnicodeNanoid<8, CyrillicAlphabet> = UnicodeNanoid::new();
+/// let id: UnicodeNanoid<8, CyrillicAlphabet> = "бежлгаим".parse()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Note that the alphabet must be non-empty, contain only unique characters, and have at most 4096 symbols.
+/// A compilation error occurs if any of these requirements is violated.
+///
+/// ```compile_fail
+/// use nid::{alphabet::UnicodeAlphabet, UnicodeNanoid};
+///
+/// struct CustomAlphabet;
+///
+/// impl UnicodeAlphabet for CustomAlphabet {
+///     const SYMBOL_LIST: &'static [char] = &['🍎', '🍊', '🍎'];
+/// }
+///
+/// let id: UnicodeNanoid<8, CustomAlphabet> = UnicodeNanoid::new(); // Compilation error: found duplicate symbol in alphabet
+/// ```
+pub trait UnicodeAlphabet {
+    /// The symbols that can be used in Nano ID.
+    const SYMBOL_LIST: &'static [char];
+}
+
+/// The largest number of symbols in a [`UnicodeAlphabet`].
+///
+/// The sorted symbols are stored in an array of this size, since the length of an array can't depend on the alphabet.
+const MAX_UNICODE_ALPHABET_LEN: usize = 4096;
+
+/// An extension trait for [`UnicodeAlphabet`] that provides additional constants.
+pub(crate) trait UnicodeAlphabetExt {
+    /// The symbols that can be used in Nano ID.
+    ///
+    /// This is the same as [`UnicodeAlphabet::SYMBOL_LIST`], but with the guarantee that it is non-empty and all elements are unique.
+    const VALID_SYMBOL_LIST: &'static [char];
+
+    /// The symbols sorted by code point, which can be searched with [`slice::binary_search`].
+    ///
+    /// This has the same guarantees as [`UnicodeAlphabetExt::VALID_SYMBOL_LIST`].
+    const SORTED_SYMBOL_LIST: &'static [char];
+
+    /// The symbols sorted by code point, followed by padding up to [`MAX_UNICODE_ALPHABET_LEN`].
+    const SORTED_SYMBOL_ARRAY: [char; MAX_UNICODE_ALPHABET_LEN];
+}
+
+impl<A: UnicodeAlphabet> UnicodeAlphabetExt for A {
+    const VALID_SYMBOL_LIST: &'static [char] = {
+        // The alphabet is validated along with the sorted symbols.
+        assert!(Self::SORTED_SYMBOL_LIST.len() == A::SYMBOL_LIST.len());
+        A::SYMBOL_LIST
+    };
+
+    const SORTED_SYMBOL_LIST: &'static [char] = {
+        assert!(!A::SYMBOL_LIST.is_empty(), "alphabet must not be empty");
+        let sorted: &'static [char; MAX_UNICODE_ALPHABET_LEN] = &Self::SORTED_SYMBOL_ARRAY;
+        let sorted = sorted.split_at(A::SYMBOL_LIST.len()).0;
+        // Duplicates are adjacent once the symbols are sorted.
+        let mut i = 1;
+        while i < sorted.len() {
+            assert!(
+                sorted[i - 1] != sorted[i],
+                "found duplicate symbol in alphabet"
+            );
+            i += 1;
+        }
+        sorted
+    };
+
+    const SORTED_SYMBOL_ARRAY: [char; MAX_UNICODE_ALPHABET_LEN] = {
+        assert!(
+            A::SYMBOL_LIST.len() <= MAX_UNICODE_ALPHABET_LEN,
+            "alphabet must not have more than 4096 symbols"
+        );
+        let mut sorted = ['\0'; MAX_UNICODE_ALPHABET_LEN];
+        let mut i = 0;
+        while i < A::SYMBOL_LIST.len() {
+            sorted[i] = A::SYMBOL_LIST[i];
+            i += 1;
+        }
+        heap_sort(sorted.split_at_mut(A::SYMBOL_LIST.len()).0);
+        sorted
+    };
+}
+
+/// Sort characters in place in `O(n log n)` time, which is fast enough for constant evaluation.
+const fn heap_sort(s: &mut [char]) {
+    // Build a max-heap, then move the largest element to the end one by one.
+    let mut start = s.len() / 2;
+    while start > 0 {
+        start -= 1;
+        sift_down(s, start, s.len());
+    }
+    let mut end = s.len();
+    while end > 1 {
+        end -= 1;
+        s.swap(0, end);
+        sift_down(s, 0, end);
+    }
+}
+
+/// Move the element at `root` down the max-heap of the first `end` elements until the heap property holds.
+const fn sift_down(s: &mut [char], mut root: usize, end: usize) {
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= end {
+            return;
+        }
+        if child + 1 < end && s[child] < s[child + 1] {
+            child += 1;
+        }
+        if s[root] >= s[child] {
+            return;
+        }
+        s.swap(root, child);
+        root = child;
+    }
 }
 
 /// Assert that all elements are unique.
@@ -151,8 +475,350 @@ const fn assert_all_ascii(s: &[u8]) {
     }
 }
 
+/// Define a custom [`Alphabet`] from character ranges and single characters.
+///
+/// This macro defines a unit struct that implements [`Alphabet`] and [`Debug`], with a doc comment describing its symbols.
+/// The symbols are listed in the order they are written, and the characters in the optional `except` string are removed.
+///
+/// # Syntax
+///
+/// ```text
+/// alphabet!(
+///     #[attributes]
+///     visibility Name = 'a'..='z', '_', ... except "excluded"
+/// );
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use nid::{alphabet::alphabet, Nanoid};
+///
+/// alphabet!(pub NoLookalikes = 'A'..='Z', 'a'..='z', '2'..='9' except "IlO");
+///
+/// let id: Nanoid<10, NoLookalikes> = Nanoid::new();
+/// let id: Nanoid<10, NoLookalikes> = "Ab2xYz9kLm".parse()?;
+/// assert!("Ab2xYz9kLO".parse::<Nanoid<10, NoLookalikes>>().is_err());
+///
+/// alphabet!(
+///     /// Lowercase letters, digits and a hyphen.
+///     Hostname = 'a'..='z', '0'..='9', '-'
+/// );
+///
+/// let id: Nanoid<8, Hostname> = "web-01ab".parse()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Compilation errors
+///
+/// The alphabet is validated at compile time.
+///
+/// ```compile_fail
+/// use nid::alphabet::alphabet;
+/// alphabet!(Overlapping = 'a'..='z', 'x'..='z'); // Compilation error: found duplicate symbol in alphabet
+/// ```
+///
+/// ```compile_fail
+/// use nid::alphabet::alphabet;
+/// alphabet!(Reversed = 'z'..='a'); // Compilation error: range start must not be greater than end
+/// ```
+///
+/// ```compile_fail
+/// use nid::alphabet::alphabet;
+/// alphabet!(Kana = 'ア'..='ン'); // Compilation error: found non-ascii symbol in alphabet
+/// ```
+///
+/// ```compile_fail
+/// use nid::alphabet::alphabet;
+/// alphabet!(Digits = '0'..='9' except "O"); // Compilation error: excluded symbol is not in alphabet
+/// ```
+///
+/// ```compile_fail
+/// use nid::alphabet::alphabet;
+/// alphabet!(Nothing = '0'..='1' except "01"); // Compilation error: alphabet must not be empty
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alphabet {
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident = $($start:literal $(..= $end:literal)?),+ $(except $except:literal)? $(,)?
+    ) => {
+        $(#[$attr])*
+        #[doc = $crate::std::concat!(
+            " Alphabet with `",
+            $crate::std::stringify!($($start $(..= $end)?),+),
+            "` symbols",
+            $(" excluding `", $except, "`",)?
+            ".",
+        )]
+        #[derive(Debug)]
+        $vis struct $name;
+
+        impl $crate::alphabet::Alphabet for $name {
+            const SYMBOL_LIST: &'static [u8] = {
+                const BUILDER: $crate::alphabet::__private::AlphabetBuilder = $crate::alphabet::__private::AlphabetBuilder::new()
+                    $(.add_range($start, $crate::__alphabet!(@end $start $(, $end)?)))+
+                    $(.except($except))?;
+                const SYMBOLS: [u8; BUILDER.symbol_count()] = BUILDER.build();
+                &SYMBOLS
+            };
+        }
+    };
+    (@end $start:literal) => {
+        $start
+    };
+    (@end $start:literal, $end:literal) => {
+        $end
+    };
+}
+
+#[doc(inline)]
+pub use crate::__alphabet as alphabet;
+
+/// Items used by [`alphabet!`] and the combined alphabets. They are not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    /// A helper for [`alphabet!`](super::alphabet!) to build a list of symbols at compile time.
+    #[derive(Debug, Clone, Copy)]
+    pub struct AlphabetBuilder {
+        symbols: [u8; 128],
+        len: usize,
+    }
+
+    impl AlphabetBuilder {
+        #[allow(clippy::new_without_default)]
+        pub const fn new() -> Self {
+            Self {
+                symbols: [0; 128],
+                len: 0,
+            }
+        }
+
+        /// Add the characters from `start` to `end`, inclusive.
+        pub const fn add_range(mut self, start: char, end: char) -> Self {
+            assert!(
+                start as u32 <= end as u32,
+                "range start must not be greater than end"
+            );
+            assert!(end.is_ascii(), "found non-ascii symbol in alphabet");
+
+            let mut symbol = start as u8;
+            while symbol <= end as u8 {
+                self = self.push(symbol);
+                symbol += 1;
+            }
+            self
+        }
+
+        /// Remove the characters in `excluded`.
+        pub const fn except(mut self, excluded: &str) -> Self {
+            let excluded = excluded.as_bytes();
+            let mut i = 0;
+            while i < excluded.len() {
+                let position = self.position(excluded[i]);
+                assert!(position < self.len, "excluded symbol is not in alphabet");
+
+                let mut j = position;
+                while j + 1 < self.len {
+                    self.symbols[j] = self.symbols[j + 1];
+                    j += 1;
+                }
+                self.len -= 1;
+                i += 1;
+            }
+            self
+        }
+
+        pub const fn symbol_count(&self) -> usize {
+            self.len
+        }
+
+        pub const fn build<const N: usize>(&self) -> [u8; N] {
+            assert!(N == self.len);
+            assert!(N > 0, "alphabet must not be empty");
+
+            let mut symbols = [0; N];
+            let mut i = 0;
+            while i < N {
+                symbols[i] = self.symbols[i];
+                i += 1;
+            }
+            symbols
+        }
+
+        /// Add the symbols in `symbols` that are in `other` if `in_other` is `true`, or not in `other` otherwise.
+        pub(super) const fn extend_filtered(
+            mut self,
+            symbols: &[u8],
+            other: &[u8],
+            in_other: bool,
+        ) -> Self {
+            let mut i = 0;
+            while i < symbols.len() {
+                if contains(other, symbols[i]) == in_other {
+                    self = self.push(symbols[i]);
+                }
+                i += 1;
+            }
+            self
+        }
+
+        const fn push(mut self, symbol: u8) -> Self {
+            assert!(
+                self.position(symbol) == self.len,
+                "found duplicate symbol in alphabet"
+            );
+            self.symbols[self.len] = symbol;
+            self.len += 1;
+            self
+        }
+
+        /// Get the symbols added so far.
+        pub(super) const fn symbols(&self) -> &[u8] {
+            self.symbols.split_at(self.len).0
+        }
+
+        /// Find the position of the symbol, or `len` if it is not added.
+        const fn position(&self, symbol: u8) -> usize {
+            let mut i = 0;
+            while i < self.len && self.symbols[i] != symbol {
+                i += 1;
+            }
+            i
+        }
+    }
+
+    /// Return `true` if the symbols contain the symbol.
+    const fn contains(symbols: &[u8], symbol: u8) -> bool {
+        let mut i = 0;
+        while i < symbols.len() {
+            if symbols[i] == symbol {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+}
+
+/// An alphabet with the symbols of `A` followed by the symbols of `B` that are not in `A`.
+///
+/// Combined alphabets are computed at compile time, and their symbols are validated like any other alphabet.
+/// The aliases of `A` and `B` are not kept.
+///
+/// # Examples
+///
+/// ```
+/// use nid::{
+///     alphabet::{alphabet, Alphabet, Base36Alphabet, Union},
+///     Nanoid,
+/// };
+///
+/// alphabet!(Underscore = '_');
+/// type Base36WithUnderscore = Union<Base36Alphabet, Underscore>;
+///
+/// assert_eq!(Base36WithUnderscore::SYMBOL_LIST, b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_");
+/// let id: Nanoid<10, Base36WithUnderscore> = "ABC_123_XY".parse()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct Union<A, B>(PhantomData<fn() -> (A, B)>);
+
+/// An alphabet with the symbols of `A` that are not in `B`.
+///
+/// Combined alphabets are computed at compile time, and their symbols are validated like any other alphabet.
+/// The aliases of `A` and `B` are not kept.
+///
+/// # Examples
+///
+/// ```
+/// use nid::{
+///     alphabet::{alphabet, Alphabet, Base62Alphabet, Difference},
+///     Nanoid,
+/// };
+///
+/// alphabet!(Lookalikes = '0', '1', 'I', 'O', 'l', 'o');
+/// type Base62WithoutLookalikes = Difference<Base62Alphabet, Lookalikes>;
+///
+/// assert_eq!(Base62WithoutLookalikes::SYMBOL_LIST.len(), 56);
+/// assert!("O0000".parse::<Nanoid<5, Base62WithoutLookalikes>>().is_err());
+/// ```
+///
+/// The following fails to compile because the resulting alphabet is empty.
+///
+/// ```compile_fail
+/// use nid::{alphabet::{Base62Alphabet, Difference}, Nanoid};
+///
+/// let id: Nanoid<21, Difference<Base62Alphabet, Base62Alphabet>> = Nanoid::new(); // Compilation error: alphabet must not be empty
+/// ```
+pub struct Difference<A, B>(PhantomData<fn() -> (A, B)>);
+
+/// An alphabet with the symbols of `A` that are also in `B`.
+///
+/// Combined alphabets are computed at compile time, and their symbols are validated like any other alphabet.
+/// The aliases of `A` and `B` are not kept.
+///
+/// # Examples
+///
+/// ```
+/// use nid::alphabet::{Alphabet, Base16LowercaseAlphabet, Base36LowercaseAlphabet, Intersection};
+///
+/// type Hex = Intersection<Base36LowercaseAlphabet, Base16LowercaseAlphabet>;
+/// assert_eq!(Hex::SYMBOL_LIST, b"abcdef0123456789");
+/// ```
+pub struct Intersection<A, B>(PhantomData<fn() -> (A, B)>);
+
+impl<A: Alphabet, B: Alphabet> Union<A, B> {
+    const BUILDER: &'static AlphabetBuilder = &AlphabetBuilder::new()
+        .extend_filtered(A::VALID_SYMBOL_LIST, &[], false)
+        .extend_filtered(B::VALID_SYMBOL_LIST, A::VALID_SYMBOL_LIST, false);
+}
+
+impl<A: Alphabet, B: Alphabet> Alphabet for Union<A, B> {
+    const SYMBOL_LIST: &'static [u8] = Self::BUILDER.symbols();
+}
+
+impl<A: Alphabet, B: Alphabet> Difference<A, B> {
+    const BUILDER: &'static AlphabetBuilder =
+        &AlphabetBuilder::new().extend_filtered(A::VALID_SYMBOL_LIST, B::VALID_SYMBOL_LIST, false);
+}
+
+impl<A: Alphabet, B: Alphabet> Alphabet for Difference<A, B> {
+    const SYMBOL_LIST: &'static [u8] = Self::BUILDER.symbols();
+}
+
+impl<A: Alphabet, B: Alphabet> Intersection<A, B> {
+    const BUILDER: &'static AlphabetBuilder =
+        &AlphabetBuilder::new().extend_filtered(A::VALID_SYMBOL_LIST, B::VALID_SYMBOL_LIST, true);
+}
+
+impl<A: Alphabet, B: Alphabet> Alphabet for Intersection<A, B> {
+    const SYMBOL_LIST: &'static [u8] = Self::BUILDER.symbols();
+}
+
+impl<A, B> std::fmt::Debug for Union<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Union")
+    }
+}
+
+impl<A, B> std::fmt::Debug for Difference<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Difference")
+    }
+}
+
+impl<A, B> std::fmt::Debug for Intersection<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Intersection")
+    }
+}
+
 macro_rules! define_and_impl_alphabet {
     ($name:ident, $symbols:expr, $description:expr $(,)?) => {
+        define_and_impl_alphabet!($name, $symbols, &[], $description);
+    };
+    ($name:ident, $symbols:expr, $aliases:expr, $description:expr $(,)?) => {
         #[doc = concat!(" ", $description, "
 
  # Example
@@ -166,6 +832,7 @@ macro_rules! define_and_impl_alphabet {
 
         impl Alphabet for $name {
             const SYMBOL_LIST: &'static [u8] = $symbols;
+            const ALIASES: &'static [(u8, u8)] = $aliases;
         }
     };
 }
@@ -203,7 +870,10 @@ define_and_impl_alphabet!(
 define_and_impl_alphabet!(
     Base32Alphabet,
     b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
-    "Alphabet with `A-Z2-7` symbols.",
+    &LOWERCASE_ALIASES,
+    "Alphabet with `A-Z2-7` symbols. Lowercase letters `a-z` are also accepted when parsing, and normalized to uppercase.
+
+ **Breaking change:** versions up to 5.0.0 rejected lowercase letters with [`ParseError::InvalidCharacter`](crate::ParseError::InvalidCharacter). Check that the string is uppercase before parsing if lowercase input must still be rejected.",
 );
 
 define_and_impl_alphabet!(
@@ -212,6 +882,13 @@ define_and_impl_alphabet!(
     "Alphabet with `a-z2-7` symbols.",
 );
 
+define_and_impl_alphabet!(
+    Base32CrockfordAlphabet,
+    b"0123456789ABCDEFGHJKMNPQRSTVWXYZ",
+    &BASE32_CROCKFORD_ALIASES,
+    "Alphabet with `0-9A-Z` symbols excluding `ILOU`, which is Crockford's Base32. Lowercase letters `a-z` except `u` are also accepted when parsing, and `I`, `L` and `O` are read as `1`, `1` and `0`. See also [`Nanoid::try_from_crockford`](crate::Nanoid::try_from_crockford).",
+);
+
 define_and_impl_alphabet!(
     Base16Alphabet,
     b"ABCDEF0123456789",
@@ -239,6 +916,7 @@ mod tests {
         assert_eq!(Base36LowercaseAlphabet::SYMBOL_LIST.len(), 36);
         assert_eq!(Base32Alphabet::SYMBOL_LIST.len(), 32);
         assert_eq!(Base32LowercaseAlphabet::SYMBOL_LIST.len(), 32);
+        assert_eq!(Base32CrockfordAlphabet::SYMBOL_LIST.len(), 32);
         assert_eq!(Base16Alphabet::SYMBOL_LIST.len(), 16);
         assert_eq!(Base16LowercaseAlphabet::SYMBOL_LIST.len(), 16);
     }
@@ -264,4 +942,120 @@ mod tests {
         assert!(Base16LowercaseAlphabet::VALID_SYMBOL_MAP[b'a' as usize]);
         assert!(!Base16LowercaseAlphabet::VALID_SYMBOL_MAP[b'z' as usize]);
     }
+
+    #[test]
+    fn test_alphabet_digits() {
+        fn inner<A: Alphabet>(expected: &[u8]) {
+            assert_eq!(&A::DIGIT_LIST[..A::VALID_SYMBOL_LIST.len()], expected);
+            for (i, &symbol) in expected.iter().enumerate() {
+                assert_eq!(A::DIGIT_MAP[symbol as usize] as usize, i);
+            }
+            for (i, &symbol) in A::VALID_SYMBOL_LIST.iter().enumerate() {
+                assert_eq!(A::SYMBOL_INDEX_MAP[symbol as usize] as usize, i);
+            }
+        }
+
+        inner::<Base64UrlAlphabet>(
+            b"-0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz",
+        );
+        inner::<Base62Alphabet>(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+        inner::<Base58Alphabet>(b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+        inner::<Base36Alphabet>(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+        inner::<Base32Alphabet>(b"234567ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+        inner::<Base32CrockfordAlphabet>(b"0123456789ABCDEFGHJKMNPQRSTVWXYZ");
+        inner::<Base16Alphabet>(b"0123456789ABCDEF");
+        inner::<Base16LowercaseAlphabet>(b"0123456789abcdef");
+    }
+
+    #[test]
+    fn test_alphabet_macro() {
+        alphabet!(NoLookalikes = 'A'..='Z', 'a'..='z', '2'..='9' except "IlO");
+        alphabet!(Hostname = 'a'..='z', '0'..='9', '-');
+        alphabet!(Single = '!');
+
+        assert_eq!(
+            NoLookalikes::SYMBOL_LIST,
+            b"ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz23456789"
+        );
+        assert_eq!(
+            Hostname::SYMBOL_LIST,
+            b"abcdefghijklmnopqrstuvwxyz0123456789-"
+        );
+        assert_eq!(Single::SYMBOL_LIST, b"!");
+        assert_eq!(format!("{NoLookalikes:?}"), "NoLookalikes");
+    }
+
+    #[test]
+    fn test_alphabet_combinators() {
+        alphabet!(Lookalikes = '0', '1', 'I', 'O', 'l', 'o');
+        alphabet!(Symbols = '_', '-', 'A');
+
+        assert_eq!(
+            Union::<Base36Alphabet, Symbols>::SYMBOL_LIST,
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_-"
+        );
+        assert_eq!(
+            Union::<Symbols, Base16Alphabet>::SYMBOL_LIST,
+            b"_-ABCDEF0123456789"
+        );
+        assert_eq!(
+            Difference::<Base62Alphabet, Lookalikes>::SYMBOL_LIST,
+            b"ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnpqrstuvwxyz23456789"
+        );
+        assert_eq!(
+            Intersection::<Base64UrlAlphabet, Symbols>::SYMBOL_LIST,
+            b"A_-"
+        );
+        assert_eq!(
+            Intersection::<Base16Alphabet, Base36Alphabet>::SYMBOL_LIST,
+            Base16Alphabet::SYMBOL_LIST
+        );
+
+        // Combinators can be nested, and the results are valid alphabets.
+        type Nested = Union<Difference<Base58Alphabet, Base16Alphabet>, Symbols>;
+        assert_eq!(
+            Nested::VALID_SYMBOL_LIST,
+            b"GHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz_-A"
+        );
+        assert_eq!(&Nested::DIGIT_LIST[..3], b"-AG");
+    }
+
+    #[test]
+    fn test_alphabet_normalize_map() {
+        assert_eq!(Base32Alphabet::NORMALIZE_MAP[b'A' as usize], b'A');
+        assert_eq!(Base32Alphabet::NORMALIZE_MAP[b'a' as usize], b'A');
+        assert_eq!(Base32Alphabet::NORMALIZE_MAP[b'z' as usize], b'Z');
+        assert_eq!(Base32Alphabet::NORMALIZE_MAP[b'2' as usize], b'2');
+        assert_eq!(Base32Alphabet::NORMALIZE_MAP[b'8' as usize], INVALID_SYMBOL);
+        assert_eq!(Base32LowercaseAlphabet::NORMALIZE_MAP[b'a' as usize], b'a');
+        assert_eq!(
+            Base32LowercaseAlphabet::NORMALIZE_MAP[b'A' as usize],
+            INVALID_SYMBOL
+        );
+        assert_eq!(Base36Alphabet::NORMALIZE_MAP[b'a' as usize], INVALID_SYMBOL);
+        assert_eq!(Base32CrockfordAlphabet::NORMALIZE_MAP[b'a' as usize], b'A');
+        assert_eq!(Base32CrockfordAlphabet::NORMALIZE_MAP[b'i' as usize], b'1');
+        assert_eq!(Base32CrockfordAlphabet::NORMALIZE_MAP[b'I' as usize], b'1');
+        assert_eq!(Base32CrockfordAlphabet::NORMALIZE_MAP[b'l' as usize], b'1');
+        assert_eq!(Base32CrockfordAlphabet::NORMALIZE_MAP[b'L' as usize], b'1');
+        assert_eq!(Base32CrockfordAlphabet::NORMALIZE_MAP[b'o' as usize], b'0');
+        assert_eq!(Base32CrockfordAlphabet::NORMALIZE_MAP[b'O' as usize], b'0');
+        assert_eq!(
+            Base32CrockfordAlphabet::NORMALIZE_MAP[b'U' as usize],
+            INVALID_SYMBOL
+        );
+        assert_eq!(
+            Base32CrockfordAlphabet::NORMALIZE_MAP[b'u' as usize],
+            INVALID_SYMBOL
+        );
+        assert_eq!(
+            Base32CrockfordAlphabet::NORMALIZE_MAP[b'-' as usize],
+            INVALID_SYMBOL
+        );
+        assert_eq!(Base64UrlAlphabet::NORMALIZE_MAP[b'-' as usize], b'-');
+        assert_eq!(
+            Base64UrlAlphabet::NORMALIZE_MAP[b':' as usize],
+            INVALID_SYMBOL
+        );
+    }
 }
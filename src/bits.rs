@@ -0,0 +1,129 @@
+use rand::{Rng, RngExt};
+
+/// The number of 64-bit words requested from the random number generator at once.
+const BLOCK_LEN: usize = 32;
+
+/// A source of random bits that draws randomness from a random number generator in large blocks.
+///
+/// Bits that are not used by one sample are kept for the next one, so consecutive samples,
+/// even across different IDs, share the same block of randomness.
+pub(crate) struct BitReader<R> {
+    rng: R,
+    block: [u64; BLOCK_LEN],
+    /// The index of the next unused word in `block`.
+    index: usize,
+    /// The unused bits of the current word, starting from the least significant bit.
+    word: u64,
+    /// The number of unused bits in `word`.
+    remaining: u32,
+}
+
+impl<R: Rng> BitReader<R> {
+    pub(crate) fn new(rng: R) -> Self {
+        Self {
+            rng,
+            block: [0; BLOCK_LEN],
+            index: BLOCK_LEN,
+            word: 0,
+            remaining: 0,
+        }
+    }
+
+    /// Take `count` random bits, where `count` is between 1 and 63.
+    #[inline]
+    pub(crate) fn bits(&mut self, count: u32) -> u64 {
+        debug_assert!(0 < count && count < 64);
+
+        if self.remaining < count {
+            if self.index == BLOCK_LEN {
+                self.rng.fill(&mut self.block[..]);
+                self.index = 0;
+            }
+            self.word = self.block[self.index];
+            self.index += 1;
+            self.remaining = 64;
+        }
+
+        let value = self.word & ((1 << count) - 1);
+        self.word >>= count;
+        self.remaining -= count;
+        value
+    }
+
+    /// Sample a number uniformly from `0..len`.
+    ///
+    /// This takes the smallest number of bits that can represent `len - 1` and rejects values that are out of range.
+    #[inline]
+    pub(crate) fn sample(&mut self, len: usize) -> usize {
+        if len == 1 {
+            return 0;
+        }
+
+        let count = (len - 1).ilog2() + 1;
+        loop {
+            let value = self.bits(count) as usize;
+            if value < len {
+                return value;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_sample_range() {
+        let mut reader = BitReader::new(rand::rngs::StdRng::seed_from_u64(0));
+        for len in 1..=128 {
+            let mut seen = vec![false; len];
+            for _ in 0..len * 100 {
+                seen[reader.sample(len)] = true;
+            }
+            assert!(seen.iter().all(|&s| s));
+        }
+    }
+
+    #[test]
+    fn test_bits_reuse_block() {
+        struct CountingRng<R> {
+            rng: R,
+            calls: usize,
+        }
+
+        impl<R: Rng> rand::TryRng for CountingRng<R> {
+            type Error = std::convert::Infallible;
+
+            fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+                self.calls += 1;
+                Ok(self.rng.next_u32())
+            }
+
+            fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+                self.calls += 1;
+                Ok(self.rng.next_u64())
+            }
+
+            fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+                self.calls += 1;
+                self.rng.fill_bytes(dst);
+                Ok(())
+            }
+        }
+
+        let mut rng = CountingRng {
+            rng: rand::rngs::StdRng::seed_from_u64(0),
+            calls: 0,
+        };
+        let mut reader = BitReader::new(&mut rng);
+        // Each word holds 10 samples of 6 bits.
+        for _ in 0..BLOCK_LEN * 10 {
+            reader.bits(6);
+        }
+        assert_eq!(rng.calls, 1);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{
+    Nanoid,
+    alphabet::{Alphabet, AlphabetExt},
+    bits::BitReader,
+};
+
+/// Bulk generation of Nano IDs.
+///
+/// These functions draw randomness from the random number generator in large blocks and reuse
+/// leftover random bits across IDs, which is much faster than calling [`Nanoid::new`] in a loop.
+impl<const N: usize, A: Alphabet> Nanoid<N, A> {
+    /// Fill the slice with new Nano IDs using random number generator seeded by the system.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    ///
+    /// let mut ids = [Nanoid::<21>::MIN; 100];
+    /// Nanoid::fill(&mut ids);
+    /// ```
+    pub fn fill(ids: &mut [Self]) {
+        Self::fill_with(ids, crate::system_rng());
+        ids.iter().for_each(crate::track_generated);
+    }
+
+    /// Fill the slice with new Nano IDs using the provided random number generator.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    ///
+    /// let mut ids = vec![Nanoid::<21>::MIN; 100];
+    /// Nanoid::fill_with(&mut ids, rand::rng());
+    /// ```
+    pub fn fill_with(ids: &mut [Self], rng: impl rand::Rng) {
+        let mut reader = BitReader::new(rng);
+        for id in ids {
+            *id = Self::new_with_reader(&mut reader);
+        }
+    }
+
+    /// Generate an array of new Nano IDs using random number generator seeded by the system.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    /// let ids: [Nanoid; 100] = Nanoid::new_array();
+    /// ```
+    #[must_use]
+    pub fn new_array<const M: usize>() -> [Self; M] {
+        let ids = Self::new_array_with(crate::system_rng());
+        ids.iter().for_each(crate::track_generated);
+        ids
+    }
+
+    /// Generate an array of new Nano IDs using the provided random number generator.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    /// let ids: [Nanoid; 100] = Nanoid::new_array_with(rand::rng());
+    /// ```
+    #[must_use]
+    pub fn new_array_with<const M: usize>(rng: impl rand::Rng) -> [Self; M] {
+        let mut reader = BitReader::new(rng);
+        std::array::from_fn(|_| Self::new_with_reader(&mut reader))
+    }
+
+    /// Generate a vector of new Nano IDs using random number generator seeded by the system.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    /// let ids: Vec<Nanoid> = Nanoid::new_vec(100);
+    /// assert_eq!(ids.len(), 100);
+    /// ```
+    #[must_use]
+    pub fn new_vec(count: usize) -> Vec<Self> {
+        let ids = Self::new_vec_with(count, crate::system_rng());
+        ids.iter().for_each(crate::track_generated);
+        ids
+    }
+
+    /// Generate a vector of new Nano IDs using the provided random number generator.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    /// let ids: Vec<Nanoid> = Nanoid::new_vec_with(100, rand::rng());
+    /// assert_eq!(ids.len(), 100);
+    /// ```
+    #[must_use]
+    pub fn new_vec_with(count: usize, rng: impl rand::Rng) -> Vec<Self> {
+        let mut reader = BitReader::new(rng);
+        (0..count)
+            .map(|_| Self::new_with_reader(&mut reader))
+            .collect()
+    }
+
+    /// Generate a new Nano ID using the provided source of random bits.
+    #[inline]
+    pub(crate) fn new_with_reader(reader: &mut BitReader<impl rand::Rng>) -> Self {
+        let symbols = A::VALID_SYMBOL_LIST;
+        Self::from_inner(std::array::from_fn(|_| {
+            symbols[reader.sample(symbols.len())]
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::alphabet::{
+        Base16Alphabet, Base36Alphabet, Base58Alphabet, Base62Alphabet, Base64UrlAlphabet,
+    };
+
+    #[test]
+    fn test_bulk_unique() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let mut ids = vec![Nanoid::<N, A>::MIN; 1000];
+            Nanoid::fill(&mut ids);
+            let arr: [Nanoid<N, A>; 1000] = Nanoid::new_array();
+            let vec: Vec<Nanoid<N, A>> = Nanoid::new_vec(1000);
+
+            let set = ids.iter().chain(&arr).chain(&vec).collect::<HashSet<_>>();
+            assert_eq!(set.len(), 3000);
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<21, Base62Alphabet>();
+        inner::<21, Base58Alphabet>();
+        inner::<10, Base62Alphabet>();
+        inner::<12, Base58Alphabet>();
+    }
+
+    #[test]
+    fn test_bulk_uniformity() {
+        fn inner<const N: usize, A: Alphabet>(iterations: usize) {
+            let mut counts = HashMap::new();
+
+            for id in Nanoid::<N, A>::new_vec(iterations) {
+                for c in id.as_str().chars() {
+                    *counts.entry(c).or_insert(0) += 1;
+                }
+            }
+
+            assert_eq!(counts.len(), A::VALID_SYMBOL_LIST.len());
+
+            let max_count = counts.values().max().unwrap();
+            let min_count = counts.values().min().unwrap();
+            let expected_count = counts.values().sum::<usize>() as f64 / counts.len() as f64;
+            assert!((max_count - min_count) as f64 / expected_count < 0.05);
+        }
+
+        inner::<21, Base64UrlAlphabet>(100_000);
+        inner::<21, Base62Alphabet>(100_000);
+        inner::<21, Base58Alphabet>(100_000);
+        inner::<6, Base64UrlAlphabet>(400_000);
+        inner::<10, Base62Alphabet>(200_000);
+        inner::<12, Base58Alphabet>(200_000);
+        inner::<21, Base36Alphabet>(100_000);
+        inner::<21, Base16Alphabet>(100_000);
+    }
+
+    #[test]
+    fn test_bulk_deterministic() {
+        let ids1: [Nanoid; 10] = Nanoid::new_array_with(rand::rngs::StdRng::seed_from_u64(0));
+        let ids2 = Nanoid::new_vec_with(10, rand::rngs::StdRng::seed_from_u64(0));
+        let mut ids3 = [Nanoid::MIN; 10];
+        Nanoid::fill_with(&mut ids3, rand::rngs::StdRng::seed_from_u64(0));
+        assert_eq!(ids1.as_slice(), ids2.as_slice());
+        assert_eq!(ids1, ids3);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{
+    Nanoid, OverflowError,
+    alphabet::{Alphabet, AlphabetExt},
+    collision::length_for_entropy,
+};
+
+/// Byte array encoding.
+///
+/// A byte array is interpreted as a big-endian unsigned integer and encoded like [`Nanoid::from_u128`],
+/// so the order of encoded Nano IDs by value, as compared by [`ByValue`](crate::ByValue), is the same as the
+/// lexicographic order of the byte arrays. Like [`Nanoid::from_u128`], the encoded Nano IDs are generally **not** sorted
+/// in that order with [`Ord`].
+impl<const N: usize, A: Alphabet> Nanoid<N, A> {
+    /// Encode a byte array into a Nano ID.
+    ///
+    /// The Nano ID must be at least [`length_for_bytes::<A>(M)`](length_for_bytes) symbols long, so that
+    /// every byte array fits in it. Otherwise, calling this method will result in a compilation error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base58Alphabet, length_for_bytes, Nanoid};
+    ///
+    /// type Hash = Nanoid<{ length_for_bytes::<Base58Alphabet>(32) }, Base58Alphabet>;
+    ///
+    /// let bytes = [0xab; 32];
+    /// let id = Hash::from_byte_array(&bytes);
+    /// assert_eq!(id.as_str(), "MiHhdenuG9wt1dvwkEwGuq8buJ8aK2ZNhv8M79pbu8J3");
+    /// assert_eq!(id.to_byte_array()?, bytes);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// The following fails to compile because 16 bytes need 22 symbols in Base62.
+    ///
+    /// ```compile_fail
+    /// use nid::{alphabet::Base62Alphabet, Nanoid};
+    ///
+    /// let id: Nanoid<21, Base62Alphabet> = Nanoid::from_byte_array(&[0; 16]);
+    /// ```
+    #[must_use]
+    pub fn from_byte_array<const M: usize>(bytes: &[u8; M]) -> Self {
+        const {
+            assert!(
+                N >= length_for_bytes::<A>(M),
+                "Nano ID is too short to hold the bytes"
+            );
+        };
+
+        let mut digits = [0; N];
+        convert_radix(bytes, 256, &mut digits, A::VALID_SYMBOL_LIST.len() as u32)
+            .expect("the Nano ID is long enough to hold the bytes");
+        Self::from_inner(digits.map(|digit| A::VALID_SYMBOL_LIST[usize::from(digit)]))
+    }
+
+    /// Decode a Nano ID into a byte array.
+    ///
+    /// # Errors
+    ///
+    /// If the value of the Nano ID does not fit in `M` bytes, this method returns [`OverflowError`].
+    /// This can happen if the Nano ID was not encoded from a byte array of the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base62Alphabet, Nanoid, OverflowError};
+    ///
+    /// let id: Nanoid<22, Base62Alphabet> = Nanoid::from_byte_array(&[0xff; 16]);
+    /// assert_eq!(id.to_byte_array::<16>()?, [0xff; 16]);
+    ///
+    /// let id: Nanoid<22, Base62Alphabet> = "9999999999999999999999".parse()?;
+    /// assert_eq!(id.to_byte_array::<16>(), Err(OverflowError));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_byte_array<const M: usize>(&self) -> Result<[u8; M], OverflowError> {
+        let digits = self
+            .inner
+            .map(|symbol| A::SYMBOL_INDEX_MAP[usize::from(symbol)]);
+        let mut bytes = [0; M];
+        convert_radix(&digits, A::VALID_SYMBOL_LIST.len() as u32, &mut bytes, 256)?;
+        Ok(bytes)
+    }
+}
+
+/// Calculate the shortest length of a Nano ID with the alphabet `A` that can hold any byte array of `len` bytes.
+///
+/// This is a `const fn`, so it can be used to declare a Nano ID type for a byte array.
+/// The length is the smallest `N` such that `alphabet size ^ N >= 256 ^ len`. See also [`length_for_entropy`].
+///
+/// # Panics
+///
+/// This function panics if the alphabet has only one symbol and `len` is positive, or `len` is larger than 512.
+///
+/// # Examples
+///
+/// ```
+/// use nid::{
+///     alphabet::{Base16Alphabet, Base58Alphabet, Base62Alphabet},
+///     length_for_bytes,
+/// };
+///
+/// assert_eq!(length_for_bytes::<Base58Alphabet>(16), 22);
+/// assert_eq!(length_for_bytes::<Base58Alphabet>(32), 44);
+/// assert_eq!(length_for_bytes::<Base62Alphabet>(32), 43);
+/// assert_eq!(length_for_bytes::<Base16Alphabet>(32), 64);
+/// ```
+#[must_use]
+pub const fn length_for_bytes<A: Alphabet>(len: usize) -> usize {
+    assert!(len <= 512, "byte length must not exceed 512");
+    length_for_entropy::<A>(len as u32 * 8)
+}
+
+/// Convert a big-endian number from one radix to another, filling the whole output with left-padding.
+///
+/// Each element of `input` must be less than `from`. Each element of `output` will be less than `to`.
+/// If the number does not fit in `output`, this function returns [`OverflowError`].
+pub(crate) fn convert_radix(
+    input: &[u8],
+    from: u32,
+    output: &mut [u8],
+    to: u32,
+) -> Result<(), OverflowError> {
+    output.fill(0);
+    for &digit in input {
+        // Multiply `output` by `from` and add `digit`.
+        let mut carry = u32::from(digit);
+        for out in output.iter_mut().rev() {
+            let value = u32::from(*out) * from + carry;
+            *out = (value % to) as u8;
+            carry = value / to;
+        }
+        if carry != 0 {
+            return Err(OverflowError);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::alphabet::{
+        Base16Alphabet, Base32Alphabet, Base36Alphabet, Base58Alphabet, Base62Alphabet,
+        Base64UrlAlphabet,
+    };
+
+    #[test]
+    fn test_length_for_bytes() {
+        assert_eq!(length_for_bytes::<Base64UrlAlphabet>(0), 0);
+        assert_eq!(length_for_bytes::<Base64UrlAlphabet>(16), 22);
+        assert_eq!(length_for_bytes::<Base62Alphabet>(16), 22);
+        assert_eq!(length_for_bytes::<Base36Alphabet>(16), 25);
+        assert_eq!(length_for_bytes::<Base32Alphabet>(16), 26);
+        assert_eq!(length_for_bytes::<Base16Alphabet>(16), 32);
+        assert_eq!(length_for_bytes::<Base62Alphabet>(32), 43);
+    }
+
+    #[test]
+    fn test_bytes_match_integers() {
+        let value = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        let id1: Nanoid<22, Base62Alphabet> = Nanoid::from_byte_array(&value.to_be_bytes());
+        let id2: Nanoid<22, Base62Alphabet> = Nanoid::from_u128(value).unwrap();
+        assert_eq!(id1, id2);
+    }
+
+    #[test]
+    fn test_bytes_roundtrip_and_order() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let values: [[u8; 32]; 6] = [
+                [0; 32],
+                std::array::from_fn(|i| (i == 31) as u8),
+                std::array::from_fn(|i| i as u8),
+                std::array::from_fn(|i| (i * 37 + 11) as u8),
+                std::array::from_fn(|i| if i == 0 { 0xff } else { 0 }),
+                [0xff; 32],
+            ];
+
+            let mut prev: Option<Nanoid<N, A>> = None;
+            for bytes in values {
+                let id: Nanoid<N, A> = Nanoid::from_byte_array(&bytes);
+                assert_eq!(id.to_byte_array(), Ok(bytes));
+                if let Some(prev) = prev {
+                    assert!(prev.cmp_by_value(&id).is_lt());
+                }
+                prev = Some(id);
+            }
+        }
+
+        inner::<43, Base64UrlAlphabet>();
+        inner::<43, Base62Alphabet>();
+        inner::<44, Base58Alphabet>();
+        inner::<50, Base36Alphabet>();
+        inner::<52, Base32Alphabet>();
+        inner::<64, Base16Alphabet>();
+        inner::<70, Base58Alphabet>();
+    }
+
+    #[test]
+    fn test_bytes_overflow() {
+        let id: Nanoid<22, Base62Alphabet> = "9999999999999999999999".parse().unwrap();
+        assert_eq!(id.to_byte_array::<16>(), Err(OverflowError));
+        assert!(id.to_byte_array::<17>().is_ok());
+
+        let id: Nanoid<22, Base62Alphabet> = Nanoid::from_byte_array(&[0, 0, 0, 1]);
+        assert_eq!(id.to_byte_array::<1>(), Ok([1]));
+        assert_eq!(id.to_byte_array::<0>(), Err(OverflowError));
+    }
+}
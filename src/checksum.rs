@@ -0,0 +1,568 @@
+use crate::{
+    Nanoid, ParseError,
+    alphabet::{Alphabet, AlphabetExt, Base64UrlAlphabet},
+};
+
+/// A Nano ID followed by two check symbols, such as `kP_IH1DPMx3X`, which detects typos when parsing.
+///
+/// The check symbols detect every substitution of a single symbol and every transposition of two adjacent symbols,
+/// including the check symbols themselves.
+///
+/// # Algorithm
+///
+/// The checksum uses a prime modulus `p`, which is the largest prime not greater than `alphabet size ^ 2`.
+/// For example, `p` is `4093` for [`Base64UrlAlphabet`] and `3833` for
+/// [`Base62Alphabet`](crate::alphabet::Base62Alphabet).
+///
+/// The whole string is interpreted as a number in base `alphabet size`, where the digit value of a symbol is its
+/// position in byte order, and the two check symbols are the two-digit number below `p` that makes the whole number
+/// a multiple of `p`. Since `p` is a prime
+/// larger than the alphabet size, a single substitution or an adjacent transposition always changes the remainder.
+/// Check symbols representing a number not less than `p` are rejected, so each Nano ID has exactly one valid string.
+///
+/// # Generic parameters
+///
+/// - `N`: The length of the Nano ID, excluding the check symbols. The default is `21`.
+/// - `A`: The alphabet used in the Nano ID and the check symbols. The default is [`Base64UrlAlphabet`].
+///
+/// # Examples
+///
+/// ```
+/// use nid::{ChecksummedNanoid, Nanoid};
+///
+/// type OrderId = ChecksummedNanoid<10>;
+///
+/// // Generate a new checksummed Nano ID and print it.
+/// let id: OrderId = ChecksummedNanoid::new();
+/// println!("{}", id);
+///
+/// // Parse a string into a checksummed Nano ID and convert it back to a string.
+/// let id: OrderId = "kP_IH1DPMx3X".parse()?;
+/// assert_eq!(id.to_string(), "kP_IH1DPMx3X");
+/// assert_eq!(id.id().as_str(), "kP_IH1DPMx");
+/// assert_eq!(id.check_symbols(), "3X");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// If you try to parse a string with a typo, you will get an error.
+///
+/// ```
+/// use nid::{ChecksummedNanoid, ParseError};
+///
+/// let result: Result<ChecksummedNanoid<10>, _> = "kP_IH1DPNx3X".parse();
+/// assert_eq!(result, Err(ParseError::InvalidChecksum));
+///
+/// let result: Result<ChecksummedNanoid<10>, _> = "kP_IHD1PMx3X".parse();
+/// assert_eq!(result, Err(ParseError::InvalidChecksum));
+/// ```
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
+pub struct ChecksummedNanoid<const N: usize = 21, A: Alphabet = Base64UrlAlphabet> {
+    id: Nanoid<N, A>,
+
+    /// The check symbols. All characters are ASCII.
+    check: [u8; 2],
+}
+
+impl<const N: usize, A: Alphabet> ChecksummedNanoid<N, A> {
+    /// The prime modulus of the checksum.
+    const MODULUS: u32 = {
+        let radix = A::VALID_SYMBOL_LIST.len() as u32;
+        assert!(
+            radix >= 2,
+            "alphabet must have at least two symbols to hold a checksum"
+        );
+        largest_prime(radix * radix)
+    };
+
+    /// Generate a new checksummed Nano ID using random number generator seeded by the system.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::ChecksummedNanoid;
+    /// let id: ChecksummedNanoid = ChecksummedNanoid::new();
+    /// ```
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from_nanoid(Nanoid::new())
+    }
+
+    /// Generate a new checksummed Nano ID using the provided random number generator.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::ChecksummedNanoid;
+    /// let id: ChecksummedNanoid = ChecksummedNanoid::new_with(rand::rng());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new_with(rng: impl rand::Rng) -> Self {
+        Self::from_nanoid(Nanoid::new_with(rng))
+    }
+
+    /// Append the check symbols to a [`Nanoid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{ChecksummedNanoid, Nanoid};
+    ///
+    /// let id: Nanoid<10> = "kP_IH1DPMx".parse()?;
+    /// assert_eq!(ChecksummedNanoid::from_nanoid(id).to_string(), "kP_IH1DPMx3X");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub const fn from_nanoid(id: Nanoid<N, A>) -> Self {
+        let radix = A::VALID_SYMBOL_LIST.len() as u32;
+
+        // Append two zero digits and choose them so that the remainder becomes zero.
+        let rem = remainder::<A>(&id.inner, 0, Self::MODULUS);
+        let rem = remainder::<A>(&[A::DIGIT_LIST[0]; 2], rem, Self::MODULUS);
+        let check = (Self::MODULUS - rem) % Self::MODULUS;
+
+        Self {
+            id,
+            check: [
+                A::DIGIT_LIST[(check / radix) as usize],
+                A::DIGIT_LIST[(check % radix) as usize],
+            ],
+        }
+    }
+
+    /// Parse a string into a [`ChecksummedNanoid`].
+    ///
+    /// # Errors
+    ///
+    /// - If the length of the string is not equal to the expected length, this method returns [`ParseError::InvalidLength`].
+    /// - If the string contains a character that is not in the alphabet, this method returns [`ParseError::InvalidCharacter`].
+    /// - If the check symbols don't match, this method returns [`ParseError::InvalidChecksum`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::ChecksummedNanoid;
+    /// let id: ChecksummedNanoid<10> = ChecksummedNanoid::try_from_str("kP_IH1DPMx3X")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn try_from_str(s: &str) -> Result<Self, ParseError> {
+        let s = s.as_bytes();
+
+        if s.len() != N + 2 {
+            return Err(ParseError::InvalidLength {
+                expected: N + 2,
+                actual: s.len(),
+            });
+        }
+        let (head, check) = s.split_at(N);
+        let id = match Nanoid::<N, A>::try_from_slice(head) {
+            Ok(id) => id,
+            Err(e) => return Err(e),
+        };
+        let check = match Nanoid::<2, A>::try_from_slice(check) {
+            Ok(check) => check,
+            Err(e) => return Err(e),
+        };
+
+        // The check symbols can represent numbers up to `radix ^ 2 - 1`, but only those below the modulus are valid.
+        let value = remainder::<A>(&check.inner, 0, u32::MAX);
+        if value >= Self::MODULUS {
+            return Err(ParseError::InvalidChecksum);
+        }
+        let rem = remainder::<A>(&id.inner, 0, Self::MODULUS);
+        if remainder::<A>(&check.inner, rem, Self::MODULUS) != 0 {
+            return Err(ParseError::InvalidChecksum);
+        }
+
+        Ok(Self::from_nanoid(id))
+    }
+
+    /// Get the [`Nanoid`] part of the [`ChecksummedNanoid`], without the check symbols.
+    #[must_use]
+    #[inline]
+    pub const fn id(&self) -> Nanoid<N, A> {
+        self.id
+    }
+
+    /// Get the check symbols of the [`ChecksummedNanoid`].
+    #[must_use]
+    #[inline]
+    pub const fn check_symbols(&self) -> &str {
+        // SAFETY: all characters are ASCII.
+        unsafe { std::str::from_utf8_unchecked(&self.check) }
+    }
+}
+
+/// Continue calculating the remainder of the number represented by the symbols, starting from `remainder`.
+const fn remainder<A: Alphabet>(symbols: &[u8], mut remainder: u32, modulus: u32) -> u32 {
+    let radix = A::VALID_SYMBOL_LIST.len() as u32;
+    let mut i = 0;
+    while i < symbols.len() {
+        remainder = (remainder * radix + A::DIGIT_MAP[symbols[i] as usize] as u32) % modulus;
+        i += 1;
+    }
+    remainder
+}
+
+/// Find the largest prime not greater than `n`, which must be at least `2`.
+const fn largest_prime(mut n: u32) -> u32 {
+    loop {
+        let mut d = 2;
+        while d * d <= n && n % d != 0 {
+            d += 1;
+        }
+        if d * d > n {
+            return n;
+        }
+        n -= 1;
+    }
+}
+
+// `Copy` cannot be derived due to a limitation of the compiler.
+// https://github.com/rust-lang/rust/issues/26925
+impl<const N: usize, A: Alphabet> Copy for ChecksummedNanoid<N, A> {}
+
+// `Clone` cannot be derived as well.
+impl<const N: usize, A: Alphabet> Clone for ChecksummedNanoid<N, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// `PartialEq` cannot be derived as well.
+impl<const N: usize, A: Alphabet> PartialEq for ChecksummedNanoid<N, A> {
+    fn eq(&self, other: &Self) -> bool {
+        // The check symbols are determined by the Nano ID.
+        self.id == other.id
+    }
+}
+
+// `Eq` cannot be derived as well.
+impl<const N: usize, A: Alphabet> Eq for ChecksummedNanoid<N, A> {}
+
+// `Hash` cannot be derived as well.
+impl<const N: usize, A: Alphabet> std::hash::Hash for ChecksummedNanoid<N, A> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+// `PartialOrd` cannot be derived as well.
+impl<const N: usize, A: Alphabet> PartialOrd for ChecksummedNanoid<N, A> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// `Ord` cannot be derived as well.
+impl<const N: usize, A: Alphabet> Ord for ChecksummedNanoid<N, A> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<const N: usize, A: Alphabet> std::fmt::Debug for ChecksummedNanoid<N, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ChecksummedNanoid")
+            .field(&format_args!("\"{self}\""))
+            .finish()
+    }
+}
+
+impl<const N: usize, A: Alphabet> std::fmt::Display for ChecksummedNanoid<N, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.id.as_str())?;
+        f.write_str(self.check_symbols())
+    }
+}
+
+impl<const N: usize, A: Alphabet> From<ChecksummedNanoid<N, A>> for String {
+    fn from(id: ChecksummedNanoid<N, A>) -> Self {
+        id.to_string()
+    }
+}
+
+impl<const N: usize, A: Alphabet> From<ChecksummedNanoid<N, A>> for Nanoid<N, A> {
+    fn from(id: ChecksummedNanoid<N, A>) -> Self {
+        id.id
+    }
+}
+
+impl<const N: usize, A: Alphabet> From<Nanoid<N, A>> for ChecksummedNanoid<N, A> {
+    fn from(id: Nanoid<N, A>) -> Self {
+        Self::from_nanoid(id)
+    }
+}
+
+impl<const N: usize, A: Alphabet> TryFrom<String> for ChecksummedNanoid<N, A> {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from_str(&s)
+    }
+}
+
+impl<const N: usize, A: Alphabet> std::str::FromStr for ChecksummedNanoid<N, A> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize, A: Alphabet> serde::Serialize for ChecksummedNanoid<N, A> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize, A: Alphabet> serde::Deserialize<'de> for ChecksummedNanoid<N, A> {
+    fn deserialize<D>(deserializer: D) -> Result<ChecksummedNanoid<N, A>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::try_from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Parse [`ChecksummedNanoid`]s from strings at compile time.
+///
+/// This macro transforms a constant string into [`ChecksummedNanoid`] at compile time.
+/// If the provided string is not a valid checksummed Nano ID, the program will not compile.
+///
+/// # Arguments
+///
+/// - `$id`: The checksummed Nano ID string, including the check symbols.
+/// - `$alphabet`: The alphabet used in the Nano ID. The default is [`Base64UrlAlphabet`].
+///
+/// # Examples
+///
+/// ```
+/// use nid::{checksummed_nanoid, ChecksummedNanoid};
+///
+/// let id1 = checksummed_nanoid!("kP_IH1DPMx3X");
+/// const ID1: ChecksummedNanoid<10> = checksummed_nanoid!("kP_IH1DPMx3X");
+/// ```
+///
+/// # Compilation errors
+///
+/// If the provided string is not a valid checksummed Nano ID, the program will not compile.
+///
+/// ```compile_fail
+/// use nid::checksummed_nanoid;
+/// let id = checksummed_nanoid!("kP_IH1DPNx3X"); // Compilation error: the provided string has invalid checksum
+/// ```
+#[macro_export]
+macro_rules! checksummed_nanoid {
+    ($id:expr $(, $alphabet:ty)? $(,)?) => {
+        const {
+            match $crate::ChecksummedNanoid::<
+                { $crate::std::primitive::str::as_bytes($id).len().saturating_sub(2) }
+                $(, $alphabet)?
+            >::try_from_str($id) {
+                $crate::std::result::Result::Ok(id) => id,
+                $crate::std::result::Result::Err($crate::ParseError::InvalidLength { .. }) => {
+                    $crate::std::panic!("the provided string is too short")
+                }
+                $crate::std::result::Result::Err($crate::ParseError::InvalidCharacter(_)) => {
+                    $crate::std::panic!("the provided string has invalid character")
+                }
+                $crate::std::result::Result::Err($crate::ParseError::InvalidChecksum) => {
+                    $crate::std::panic!("the provided string has invalid checksum")
+                }
+                $crate::std::result::Result::Err(_) => $crate::std::unreachable!(),
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::alphabet::{
+        Base16Alphabet, Base32Alphabet, Base36Alphabet, Base58Alphabet, Base62Alphabet,
+    };
+
+    #[test]
+    fn test_modulus() {
+        assert_eq!(ChecksummedNanoid::<21, Base64UrlAlphabet>::MODULUS, 4093);
+        assert_eq!(ChecksummedNanoid::<21, Base62Alphabet>::MODULUS, 3833);
+        assert_eq!(ChecksummedNanoid::<21, Base58Alphabet>::MODULUS, 3361);
+        assert_eq!(ChecksummedNanoid::<21, Base36Alphabet>::MODULUS, 1291);
+        assert_eq!(ChecksummedNanoid::<21, Base32Alphabet>::MODULUS, 1021);
+        assert_eq!(ChecksummedNanoid::<21, Base16Alphabet>::MODULUS, 251);
+    }
+
+    #[test]
+    fn test_checksum_roundtrip() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            for _ in 0..100 {
+                let id: ChecksummedNanoid<N, A> = ChecksummedNanoid::new_with(&mut rng);
+                let s = id.to_string();
+                assert_eq!(s.len(), N + 2);
+                assert_eq!(s.parse(), Ok(id));
+            }
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<10, Base62Alphabet>();
+        inner::<8, Base58Alphabet>();
+        inner::<12, Base36Alphabet>();
+        inner::<16, Base16Alphabet>();
+        inner::<0, Base16Alphabet>();
+    }
+
+    #[test]
+    fn test_checksum_detects_errors() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            for _ in 0..20 {
+                let id: ChecksummedNanoid<N, A> = ChecksummedNanoid::new_with(&mut rng);
+                let s = id.to_string().into_bytes();
+
+                // Every single-symbol substitution, including the check symbols.
+                for i in 0..s.len() {
+                    for &symbol in A::VALID_SYMBOL_LIST {
+                        if symbol == s[i] {
+                            continue;
+                        }
+                        let mut typo = s.clone();
+                        typo[i] = symbol;
+                        let typo = String::from_utf8(typo).unwrap();
+                        assert_eq!(
+                            ChecksummedNanoid::<N, A>::try_from_str(&typo),
+                            Err(ParseError::InvalidChecksum),
+                            "{typo}"
+                        );
+                    }
+                }
+
+                // Every adjacent transposition, including the check symbols.
+                for i in 0..s.len() - 1 {
+                    if s[i] == s[i + 1] {
+                        continue;
+                    }
+                    let mut typo = s.clone();
+                    typo.swap(i, i + 1);
+                    let typo = String::from_utf8(typo).unwrap();
+                    assert_eq!(
+                        ChecksummedNanoid::<N, A>::try_from_str(&typo),
+                        Err(ParseError::InvalidChecksum),
+                        "{typo}"
+                    );
+                }
+            }
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<10, Base62Alphabet>();
+        inner::<8, Base58Alphabet>();
+        inner::<12, Base36Alphabet>();
+        inner::<10, Base32Alphabet>();
+        inner::<16, Base16Alphabet>();
+    }
+
+    #[test]
+    fn test_checksum_parse_errors() {
+        assert_eq!(
+            ChecksummedNanoid::<10>::try_from_str("kP_IH1DPMx"),
+            Err(ParseError::InvalidLength {
+                expected: 12,
+                actual: 10
+            })
+        );
+        assert_eq!(
+            ChecksummedNanoid::<10>::try_from_str("kP_IH1DPM#3X"),
+            Err(ParseError::InvalidCharacter(b'#'))
+        );
+        assert_eq!(
+            ChecksummedNanoid::<10>::try_from_str("kP_IH1DPMx3#"),
+            Err(ParseError::InvalidCharacter(b'#'))
+        );
+    }
+
+    #[test]
+    fn test_checksum_rejects_out_of_range_check_symbols() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let radix = A::VALID_SYMBOL_LIST.len() as u32;
+            let modulus = ChecksummedNanoid::<N, A>::MODULUS;
+
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            let mut tested = 0;
+            while tested < 10 {
+                let id: ChecksummedNanoid<N, A> = ChecksummedNanoid::new_with(&mut rng);
+                let alias = remainder::<A>(&id.check, 0, u32::MAX) + modulus;
+                if alias >= radix * radix {
+                    continue;
+                }
+
+                // The check symbols of the alias are congruent to the valid ones.
+                let alias = [
+                    A::DIGIT_LIST[(alias / radix) as usize],
+                    A::DIGIT_LIST[(alias % radix) as usize],
+                ];
+                let s = format!("{}{}", id.id(), std::str::from_utf8(&alias).unwrap());
+                assert_eq!(
+                    ChecksummedNanoid::<N, A>::try_from_str(&s),
+                    Err(ParseError::InvalidChecksum),
+                    "{s}"
+                );
+                tested += 1;
+            }
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<10, Base62Alphabet>();
+        inner::<12, Base36Alphabet>();
+        inner::<16, Base16Alphabet>();
+    }
+
+    #[test]
+    fn test_checksum_out_of_range_example() {
+        // `zx`, `zy` and `zz` represent `4093`, `4094` and `4095`, which are congruent to `--`, `-0` and `-1`.
+        for (id, valid, alias) in [
+            ("kP_IH1DPim", "--", "zx"),
+            ("kP_IH1DP38", "-0", "zy"),
+            ("kP_IH1DPOS", "-1", "zz"),
+        ] {
+            let s = format!("{id}{valid}");
+            assert_eq!(
+                ChecksummedNanoid::<10>::try_from_str(&s).map(|id| id.to_string()),
+                Ok(s)
+            );
+            assert_eq!(
+                ChecksummedNanoid::<10>::try_from_str(&format!("{id}{alias}")),
+                Err(ParseError::InvalidChecksum)
+            );
+        }
+    }
+
+    #[test]
+    fn test_checksum_const() {
+        const ID: ChecksummedNanoid<10> = match ChecksummedNanoid::try_from_str("kP_IH1DPMx3X") {
+            Ok(id) => id,
+            Err(_) => panic!(),
+        };
+        assert_eq!(ID.id().as_str(), "kP_IH1DPMx");
+    }
+}
@@ -0,0 +1,326 @@
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha256;
+
+use crate::{
+    Nanoid, OverflowError,
+    alphabet::{Alphabet, AlphabetExt},
+    name::uniform_from_stream,
+};
+
+/// The number of Feistel rounds, which is the same as FF1.
+const ROUNDS: u8 = 10;
+
+/// The smallest number of Nano IDs the cipher accepts, which is the same as FF1.
+const MIN_DOMAIN_SIZE: u128 = 1_000_000;
+
+/// A format-preserving cipher that encrypts a Nano ID into another Nano ID of the same length and alphabet.
+///
+/// This is a keyed permutation over all Nano IDs of a given length and alphabet. Use it to expose sequential numbers,
+/// such as auto-increment order numbers, without revealing how many there are.
+/// Anyone with the secret can decrypt the Nano IDs, so keep it secret.
+///
+/// # Algorithm
+///
+/// A Nano ID is interpreted as a number in base `alphabet size` like [`Nanoid::to_u128`], and its `N` digits are
+/// encrypted with an unbalanced Feistel network over the radix, similar to FF1 of NIST SP 800-38G.
+///
+/// 1. Split the digits into `A` of `N / 2` digits and `B` of the remaining digits.
+/// 2. For each round `i = 0, 1, ..., 9`, let `m` be the length of `A`. Compute `m` digits `Y` from the stream of bytes
+///    `HMAC-SHA-256(secret, i || N || alphabet size || B || counter)` for `counter = 0, 1, 2, ...`,
+///    like [`Nanoid::from_name`]. Here `i` and the alphabet size are single bytes, `N` and `counter` are
+///    big-endian 64-bit and 32-bit integers, and `B` is its digits as bytes.
+///    Then let `C = (A + Y) mod alphabet size ^ m`, `A = B` and `B = C`.
+/// 3. Concatenate `A` and `B`.
+///
+/// This is not a standardized algorithm and has not been formally reviewed. It hides the order and the count of
+/// sequential numbers, but don't use it to protect secrets.
+///
+/// A small domain can be enumerated, and a Feistel network with a single digit doesn't mix anything.
+/// Like FF1, the Nano ID must have at least two symbols and at least 1,000,000 possible values.
+/// Otherwise, calling the methods will result in a compilation error.
+///
+/// # Examples
+///
+/// ```
+/// use nid::{alphabet::Base62Alphabet, IdCipher, Nanoid};
+///
+/// let cipher = IdCipher::new(b"secret key");
+///
+/// let id1: Nanoid<10, Base62Alphabet> = cipher.encrypt_u64(1)?;
+/// let id2: Nanoid<10, Base62Alphabet> = cipher.encrypt_u64(2)?;
+/// assert_eq!(id1.as_str(), "r7kFvaocai");
+/// assert_eq!(id2.as_str(), "okBG4aixNS");
+/// assert_eq!(cipher.decrypt_u64(&id2)?, 2);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// The following fail to compile because the Nano IDs are too short.
+///
+/// ```compile_fail
+/// use nid::{alphabet::Base62Alphabet, IdCipher, Nanoid};
+///
+/// let id: Nanoid<1, Base62Alphabet> = IdCipher::new(b"secret key").encrypt_u64(1)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// ```compile_fail
+/// use nid::{alphabet::Base62Alphabet, IdCipher, Nanoid};
+///
+/// let id: Nanoid<0, Base62Alphabet> = IdCipher::new(b"secret key").encrypt(&Nanoid::new());
+/// ```
+///
+/// ```compile_fail
+/// use nid::{alphabet::Base16Alphabet, IdCipher, Nanoid};
+///
+/// // 16 ^ 4 = 65,536 possible values.
+/// let id: Nanoid<4, Base16Alphabet> = IdCipher::new(b"secret key").encrypt_u64(1)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone)]
+pub struct IdCipher {
+    mac: Hmac<Sha256>,
+}
+
+impl IdCipher {
+    /// Create a new cipher with the provided secret.
+    ///
+    /// The secret should be at least 32 bytes of random data.
+    #[must_use]
+    pub fn new(secret: impl AsRef<[u8]>) -> Self {
+        Self {
+            mac: Hmac::new_from_slice(secret.as_ref()).expect("HMAC accepts keys of any length"),
+        }
+    }
+
+    /// Encrypt a Nano ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{IdCipher, Nanoid};
+    ///
+    /// let cipher = IdCipher::new(b"secret key");
+    /// let id: Nanoid = Nanoid::new();
+    /// let encrypted = cipher.encrypt(&id);
+    /// assert_eq!(cipher.decrypt(&encrypted), id);
+    /// ```
+    #[must_use]
+    pub fn encrypt<const N: usize, A: Alphabet>(&self, id: &Nanoid<N, A>) -> Nanoid<N, A> {
+        self.feistel(id, false)
+    }
+
+    /// Decrypt a Nano ID encrypted by [`IdCipher::encrypt`].
+    #[must_use]
+    pub fn decrypt<const N: usize, A: Alphabet>(&self, id: &Nanoid<N, A>) -> Nanoid<N, A> {
+        self.feistel(id, true)
+    }
+
+    /// Encode a `u64` like [`Nanoid::from_u64`] and encrypt it.
+    ///
+    /// # Errors
+    ///
+    /// If the value does not fit in `N` symbols, this method returns [`OverflowError`].
+    pub fn encrypt_u64<const N: usize, A: Alphabet>(
+        &self,
+        value: u64,
+    ) -> Result<Nanoid<N, A>, OverflowError> {
+        Nanoid::from_u64(value).map(|id| self.encrypt(&id))
+    }
+
+    /// Decrypt a Nano ID encrypted by [`IdCipher::encrypt_u64`] into a `u64`.
+    ///
+    /// # Errors
+    ///
+    /// If the decrypted value does not fit in `u64`, this method returns [`OverflowError`].
+    /// This happens if the Nano ID was not encrypted from a `u64` with the same secret.
+    pub fn decrypt_u64<const N: usize, A: Alphabet>(
+        &self,
+        id: &Nanoid<N, A>,
+    ) -> Result<u64, OverflowError> {
+        self.decrypt(id).to_u64()
+    }
+
+    /// Run the Feistel network forwards or backwards.
+    fn feistel<const N: usize, A: Alphabet>(
+        &self,
+        id: &Nanoid<N, A>,
+        backwards: bool,
+    ) -> Nanoid<N, A> {
+        const {
+            assert!(N >= 2, "Nano ID is too short to encrypt");
+            assert!(
+                domain_size(A::VALID_SYMBOL_LIST.len(), N) >= MIN_DOMAIN_SIZE,
+                "Nano ID has too few possible values to encrypt"
+            );
+        };
+
+        let radix = A::VALID_SYMBOL_LIST.len();
+        let digits = id
+            .inner
+            .map(|symbol| A::SYMBOL_INDEX_MAP[usize::from(symbol)]);
+
+        let (a, b) = digits.split_at(N / 2);
+        let (mut a, mut b) = (a.to_vec(), b.to_vec());
+        if backwards {
+            // Undo the rounds in reverse order. Before round `i`, `B` was the current `A`.
+            for i in (0..ROUNDS).rev() {
+                let mut c = std::mem::replace(&mut b, a);
+                let y = self.round::<N>(i, &b, c.len(), radix);
+                sub_mod(&mut c, &y, radix);
+                a = c;
+            }
+        } else {
+            for i in 0..ROUNDS {
+                let y = self.round::<N>(i, &b, a.len(), radix);
+                add_mod(&mut a, &y, radix);
+                std::mem::swap(&mut a, &mut b);
+            }
+        }
+
+        let mut digits = [0; N];
+        digits[..a.len()].copy_from_slice(&a);
+        digits[a.len()..].copy_from_slice(&b);
+        Nanoid::from_inner(digits.map(|digit| A::VALID_SYMBOL_LIST[usize::from(digit)]))
+    }
+
+    /// Compute `len` pseudorandom digits for the round `i` from the right half `b`.
+    fn round<const N: usize>(&self, i: u8, b: &[u8], len: usize, radix: usize) -> Vec<u8> {
+        let prefix = self
+            .mac
+            .clone()
+            .chain_update([i])
+            .chain_update((N as u64).to_be_bytes())
+            .chain_update([radix as u8])
+            .chain_update(b);
+        let mut stream = (0u32..).flat_map(|counter| {
+            prefix
+                .clone()
+                .chain_update(counter.to_be_bytes())
+                .finalize()
+                .into_bytes()
+        });
+        (0..len)
+            .map(|_| uniform_from_stream(&mut stream, radix))
+            .collect()
+    }
+}
+
+/// Calculate `radix ^ len`, saturating at [`MIN_DOMAIN_SIZE`].
+const fn domain_size(radix: usize, len: usize) -> u128 {
+    let mut size = 1;
+    let mut i = 0;
+    while i < len && size < MIN_DOMAIN_SIZE {
+        size *= radix as u128;
+        i += 1;
+    }
+    size
+}
+
+impl std::fmt::Debug for IdCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Don't print the secret.
+        f.debug_struct("IdCipher").finish_non_exhaustive()
+    }
+}
+
+/// Add big-endian numbers `x += y` modulo `radix ^ x.len()`.
+fn add_mod(x: &mut [u8], y: &[u8], radix: usize) {
+    let mut carry = 0;
+    for (x, &y) in x.iter_mut().zip(y).rev() {
+        let sum = usize::from(*x) + usize::from(y) + carry;
+        *x = (sum % radix) as u8;
+        carry = sum / radix;
+    }
+}
+
+/// Subtract big-endian numbers `x -= y` modulo `radix ^ x.len()`.
+fn sub_mod(x: &mut [u8], y: &[u8], radix: usize) {
+    let mut borrow = 0;
+    for (x, &y) in x.iter_mut().zip(y).rev() {
+        let diff = radix + usize::from(*x) - usize::from(y) - borrow;
+        *x = (diff % radix) as u8;
+        borrow = usize::from(diff < radix);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use pretty_assertions::{assert_eq, assert_ne};
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::alphabet::{
+        Base16Alphabet, Base36LowercaseAlphabet, Base58Alphabet, Base62Alphabet, Base64UrlAlphabet,
+    };
+
+    #[test]
+    fn test_cipher_vectors() {
+        // These vectors must never change. They were computed with an independent implementation in Python.
+        fn inner<const N: usize, A: Alphabet>(value: u64, expected: &str) {
+            let cipher = IdCipher::new("secret key");
+            let id: Nanoid<N, A> = cipher.encrypt_u64(value).unwrap();
+            assert_eq!(id.as_str(), expected);
+            assert_eq!(cipher.decrypt_u64(&id), Ok(value));
+        }
+
+        inner::<10, Base62Alphabet>(1, "r7kFvaocai");
+        inner::<10, Base62Alphabet>(2, "okBG4aixNS");
+        inner::<21, Base64UrlAlphabet>(0, "SWPS2OMvSQRUIbC0Sg2AI");
+        inner::<12, Base36LowercaseAlphabet>(123_456_789, "owdmx2iedjo4");
+        inner::<11, Base58Alphabet>(u64::MAX, "bq3LXP887Ub");
+        inner::<16, Base16Alphabet>(42, "58A0DA8DCF0FAB91");
+    }
+
+    #[test]
+    fn test_cipher_roundtrip() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            let cipher = IdCipher::new("secret key");
+            for _ in 0..100 {
+                let id: Nanoid<N, A> = Nanoid::new_with(&mut rng);
+                assert_eq!(cipher.decrypt(&cipher.encrypt(&id)), id);
+            }
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<22, Base62Alphabet>();
+        inner::<7, Base58Alphabet>();
+        inner::<5, Base16Alphabet>();
+        inner::<4, Base36LowercaseAlphabet>();
+    }
+
+    #[test]
+    fn test_cipher_is_injective() {
+        // The smallest domain is too large to enumerate, so check a range of sequential numbers.
+        let cipher = IdCipher::new("secret key");
+        let encrypted: HashSet<Nanoid<5, Base16Alphabet>> = (0..10_000)
+            .map(|value| cipher.encrypt_u64(value).unwrap())
+            .collect();
+        assert_eq!(encrypted.len(), 10_000);
+    }
+
+    #[test]
+    fn test_domain_size() {
+        assert_eq!(domain_size(16, 4), 65_536);
+        assert_eq!(domain_size(16, 5), 1_048_576);
+        assert_eq!(domain_size(10, 6), MIN_DOMAIN_SIZE);
+        assert_eq!(domain_size(62, 0), 1);
+        assert!(domain_size(usize::MAX, 100) >= MIN_DOMAIN_SIZE);
+    }
+
+    #[test]
+    fn test_cipher_secret() {
+        let id1: Nanoid = IdCipher::new("secret 1").encrypt_u64(1).unwrap();
+        let id2: Nanoid = IdCipher::new("secret 2").encrypt_u64(1).unwrap();
+        assert_ne!(id1, id2);
+        assert_eq!(IdCipher::new("secret 2").decrypt_u64(&id2), Ok(1));
+    }
+
+    #[test]
+    fn test_debug_hides_secret() {
+        let cipher = IdCipher::new("hunter2");
+        assert_eq!(format!("{cipher:?}"), "IdCipher { .. }");
+    }
+}
@@ -0,0 +1,475 @@
+use std::time::Duration;
+
+use crate::{
+    Nanoid,
+    alphabet::{Alphabet, AlphabetExt},
+};
+
+impl<const N: usize, A: Alphabet> Nanoid<N, A> {
+    /// The entropy of a randomly generated Nano ID in bits, which is `N * log2(alphabet size)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base62Alphabet, Nanoid};
+    ///
+    /// assert_eq!(Nanoid::<21>::ENTROPY_BITS, 126.0);
+    /// assert!((Nanoid::<21, Base62Alphabet>::ENTROPY_BITS - 125.04).abs() < 0.01);
+    /// ```
+    pub const ENTROPY_BITS: f64 = N as f64 * log2(A::VALID_SYMBOL_LIST.len());
+
+    /// The number of possible Nano IDs, which is `alphabet size ^ N`.
+    ///
+    /// This is an `f64` since the number easily exceeds the range of integers. It is infinite if it exceeds the range of `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base16Alphabet, Nanoid};
+    ///
+    /// assert_eq!(Nanoid::<8, Base16Alphabet>::KEYSPACE, 4294967296.0);
+    /// assert_eq!(Nanoid::<21>::KEYSPACE, 2f64.powi(126));
+    /// ```
+    pub const KEYSPACE: f64 = {
+        let radix = A::VALID_SYMBOL_LIST.len() as f64;
+        let mut keyspace = 1.0;
+        let mut i = 0;
+        while i < N {
+            keyspace *= radix;
+            i += 1;
+        }
+        keyspace
+    };
+
+    /// Calculate the probability that at least one collision occurs among `count` randomly generated Nano IDs.
+    ///
+    /// `count` is an `f64` since the number of IDs may exceed the range of integers for long Nano IDs.
+    ///
+    /// This uses the same approximation of the birthday problem as
+    /// the [Nano ID Collision Calculator](https://zelark.github.io/nano-id-cc/):
+    /// `1 - exp(-count * (count - 1) / (2 * KEYSPACE))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base58Alphabet, Nanoid};
+    ///
+    /// // 1% probability after about 1.3 * 10^18 IDs.
+    /// let p = Nanoid::<21>::collision_probability(1.3e18);
+    /// assert!((p - 0.01).abs() < 0.001);
+    ///
+    /// // Short IDs collide quickly: about 32% after 10 million IDs.
+    /// let p = Nanoid::<8, Base58Alphabet>::collision_probability(1e7);
+    /// assert!(p > 0.3);
+    /// ```
+    #[must_use]
+    pub fn collision_probability(count: f64) -> f64 {
+        -(-count * (count - 1.0) / (2.0 * Self::KEYSPACE)).exp_m1()
+    }
+
+    /// Calculate the number of randomly generated Nano IDs after which a collision occurs with the given probability.
+    ///
+    /// This is the inverse of [`Nanoid::collision_probability`]: `sqrt(2 * KEYSPACE * ln(1 / (1 - probability)))`.
+    /// `probability` should be between `0` and `1`; the result is infinite for `1` and NaN outside that range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    ///
+    /// let count = Nanoid::<21>::count_for_collision_probability(0.01);
+    /// assert!((count / 1.307e18 - 1.0).abs() < 0.001);
+    /// ```
+    #[must_use]
+    pub fn count_for_collision_probability(probability: f64) -> f64 {
+        (-2.0 * Self::KEYSPACE * (-probability).ln_1p()).sqrt()
+    }
+
+    /// Calculate the time after which a collision occurs with the given probability,
+    /// when Nano IDs are generated at the rate of `ids_per_second`.
+    ///
+    /// The result saturates at [`Duration::MAX`]. Use `0.01` as the probability to get
+    /// the same result as the [Nano ID Collision Calculator](https://zelark.github.io/nano-id-cc/).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `probability` is not between `0` and `1`, or if `ids_per_second` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use nid::{alphabet::Base58Alphabet, Nanoid};
+    ///
+    /// const YEAR: f64 = 365.0 * 24.0 * 60.0 * 60.0;
+    ///
+    /// // About 149 billion years at 1000 IDs per hour.
+    /// let time = Nanoid::<21>::time_for_collision_probability(0.01, 1000.0 / 3600.0);
+    /// assert!((time.as_secs_f64() / YEAR / 149e9 - 1.0).abs() < 0.01);
+    ///
+    /// // About 67 days for 8-character Base58 IDs.
+    /// let time = Nanoid::<8, Base58Alphabet>::time_for_collision_probability(0.01, 1000.0 / 3600.0);
+    /// assert_eq!(time.as_secs() / (24 * 60 * 60), 66);
+    /// ```
+    #[must_use]
+    pub fn time_for_collision_probability(probability: f64, ids_per_second: f64) -> Duration {
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "probability must be between 0 and 1"
+        );
+        assert!(ids_per_second > 0.0, "ids_per_second must be positive");
+
+        let secs = Self::count_for_collision_probability(probability) / ids_per_second;
+        Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+    }
+
+    /// Assert at compile time that the Nano ID has at least `bits` bits of entropy.
+    ///
+    /// Use this in a constant to reject a Nano ID type that is too short for its purpose, such as a session token.
+    /// The check is exact even if the alphabet size is not a power of two. See also [`length_for_entropy`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the entropy of the Nano ID is less than `bits`. When evaluated in a constant,
+    /// it results in a compile error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base62Alphabet, Nanoid};
+    ///
+    /// type SessionToken = Nanoid<22, Base62Alphabet>;
+    /// const _: () = SessionToken::assert_min_entropy(128);
+    /// ```
+    ///
+    /// The following fails to compile because `Nanoid<8, Base16Alphabet>` has only 32 bits of entropy.
+    ///
+    /// ```compile_fail
+    /// use nid::{alphabet::Base16Alphabet, Nanoid};
+    ///
+    /// type SessionToken = Nanoid<8, Base16Alphabet>;
+    /// const _: () = SessionToken::assert_min_entropy(128);
+    /// ```
+    pub const fn assert_min_entropy(bits: u32) {
+        assert!(
+            N >= length_for_entropy::<A>(bits),
+            "Nano ID has less entropy than required"
+        );
+    }
+}
+
+/// The largest number of bits accepted by [`length_for_entropy`].
+const MAX_ENTROPY_BITS: u32 = 4096;
+
+/// Calculate the shortest length of a Nano ID with the alphabet `A` that has at least `bits` bits of entropy.
+///
+/// This is a `const fn`, so it can be used to declare a Nano ID type by target entropy.
+/// The length is the smallest `N` such that `alphabet size ^ N >= 2 ^ bits`, calculated exactly with integers.
+///
+/// # Panics
+///
+/// This function panics if the alphabet has only one symbol and `bits` is positive, or `bits` is larger than 4096.
+///
+/// # Examples
+///
+/// ```
+/// use nid::{
+///     alphabet::{Base16Alphabet, Base58Alphabet, Base62Alphabet, Base64UrlAlphabet},
+///     length_for_entropy, Nanoid,
+/// };
+///
+/// assert_eq!(length_for_entropy::<Base64UrlAlphabet>(128), 22);
+/// assert_eq!(length_for_entropy::<Base62Alphabet>(128), 22);
+/// assert_eq!(length_for_entropy::<Base58Alphabet>(128), 22);
+/// assert_eq!(length_for_entropy::<Base16Alphabet>(128), 32);
+///
+/// // A token type with at least 128 bits of entropy.
+/// type InviteToken = Nanoid<{ length_for_entropy::<Base58Alphabet>(128) }, Base58Alphabet>;
+/// let token = InviteToken::new();
+/// assert!(InviteToken::ENTROPY_BITS >= 128.0);
+/// ```
+#[must_use]
+pub const fn length_for_entropy<A: Alphabet>(bits: u32) -> usize {
+    assert!(
+        bits <= MAX_ENTROPY_BITS,
+        "entropy must not exceed 4096 bits"
+    );
+
+    // `2 ^ bits` as a little-endian big integer.
+    let mut keyspace = [0u64; LIMBS];
+    keyspace[bits as usize / 64] = 1 << (bits % 64);
+    length_for_keyspace::<A>(&keyspace)
+}
+
+/// The number of 64-bit limbs of the big integers used to calculate lengths.
+///
+/// This has one more limb than [`MAX_ENTROPY_BITS`] so that a power of the alphabet size just above the largest
+/// keyspace doesn't overflow.
+const LIMBS: usize = MAX_ENTROPY_BITS as usize / 64 + 1;
+
+/// Calculate `alphabet size ^ len` as a little-endian big integer.
+///
+/// # Panics
+///
+/// This function panics if the result exceeds `2 ^ 4096`.
+pub(crate) const fn keyspace<A: Alphabet>(len: usize) -> [u64; LIMBS] {
+    let radix = A::VALID_SYMBOL_LIST.len() as u128;
+
+    let mut value = [0u64; LIMBS];
+    value[0] = 1;
+    let mut i = 0;
+    while i < len {
+        mul_small(&mut value, radix);
+        assert!(
+            bit_length(&value) <= MAX_ENTROPY_BITS + 1,
+            "keyspace must not exceed 2 ^ 4096"
+        );
+        i += 1;
+    }
+    value
+}
+
+/// Calculate the shortest length of a Nano ID with the alphabet `A` whose keyspace is at least `keyspace`.
+///
+/// # Panics
+///
+/// This function panics if the alphabet has only one symbol and `keyspace` is larger than one.
+pub(crate) const fn length_for_keyspace<A: Alphabet>(keyspace: &[u64; LIMBS]) -> usize {
+    let radix = A::VALID_SYMBOL_LIST.len() as u128;
+
+    // `alphabet size ^ len` as a little-endian big integer.
+    let mut value = [0u64; LIMBS];
+    value[0] = 1;
+    let mut len = 0;
+    while is_less(&value, keyspace) {
+        // A keyspace of one is reached with no symbols, even if the alphabet has only one symbol.
+        assert!(radix >= 2, "alphabet must have at least two symbols");
+        mul_small(&mut value, radix);
+        len += 1;
+    }
+    len
+}
+
+/// Multiply a little-endian big integer by a small number in place.
+const fn mul_small(value: &mut [u64; LIMBS], n: u128) {
+    let mut carry = 0;
+    let mut i = 0;
+    while i < LIMBS {
+        let product = value[i] as u128 * n + carry;
+        value[i] = product as u64;
+        carry = product >> 64;
+        i += 1;
+    }
+    assert!(carry == 0, "big integer overflow");
+}
+
+/// Check whether a little-endian big integer is less than another.
+const fn is_less(a: &[u64; LIMBS], b: &[u64; LIMBS]) -> bool {
+    let mut i = LIMBS;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
+/// Get the number of bits needed to represent a little-endian big integer.
+const fn bit_length(value: &[u64]) -> u32 {
+    let mut i = value.len();
+    while i > 0 {
+        i -= 1;
+        if value[i] != 0 {
+            return i as u32 * u64::BITS + (u64::BITS - value[i].leading_zeros());
+        }
+    }
+    0
+}
+
+/// Calculate `log2(n)` at compile time with the precision of `f64`.
+const fn log2(n: usize) -> f64 {
+    assert!(n > 0, "cannot calculate the logarithm of zero");
+
+    // The integer part.
+    let int = n.ilog2();
+    // The fractional part is calculated bit by bit by squaring the mantissa in [1, 2).
+    let mut mantissa = n as f64 / (1u64 << int) as f64;
+    let mut frac = 0.0;
+    let mut bit = 0.5;
+    while bit > f64::EPSILON {
+        mantissa *= mantissa;
+        if mantissa >= 2.0 {
+            mantissa /= 2.0;
+            frac += bit;
+        }
+        bit /= 2.0;
+    }
+    int as f64 + frac
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::alphabet::{
+        Base16Alphabet, Base32Alphabet, Base36Alphabet, Base58Alphabet, Base62Alphabet,
+        Base64UrlAlphabet,
+    };
+
+    #[test]
+    fn test_log2() {
+        for n in 1..=1000 {
+            let expected = (n as f64).log2();
+            assert!((log2(n) - expected).abs() < 1e-12, "log2({n})");
+        }
+    }
+
+    #[test]
+    fn test_entropy_bits() {
+        fn inner<const N: usize, A: Alphabet>(expected: f64) {
+            assert!(
+                (Nanoid::<N, A>::ENTROPY_BITS - expected).abs() < 1e-9,
+                "{}",
+                Nanoid::<N, A>::ENTROPY_BITS
+            );
+        }
+
+        inner::<21, Base64UrlAlphabet>(126.0);
+        inner::<21, Base62Alphabet>(21.0 * 62f64.log2());
+        inner::<21, Base58Alphabet>(21.0 * 58f64.log2());
+        inner::<16, Base36Alphabet>(16.0 * 36f64.log2());
+        inner::<16, Base32Alphabet>(80.0);
+        inner::<10, Base16Alphabet>(40.0);
+        inner::<0, Base64UrlAlphabet>(0.0);
+    }
+
+    #[test]
+    fn test_keyspace() {
+        assert_eq!(Nanoid::<0>::KEYSPACE, 1.0);
+        assert_eq!(Nanoid::<1>::KEYSPACE, 64.0);
+        assert_eq!(Nanoid::<5, Base62Alphabet>::KEYSPACE, 916_132_832.0);
+        assert_eq!(Nanoid::<21, Base16Alphabet>::KEYSPACE, 2f64.powi(84));
+        assert_eq!(Nanoid::<200>::KEYSPACE, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_collision_probability() {
+        assert_eq!(Nanoid::<21>::collision_probability(0.0), 0.0);
+        assert_eq!(Nanoid::<21>::collision_probability(1.0), 0.0);
+        // Two IDs collide with the probability of 1 / KEYSPACE.
+        let p = Nanoid::<4, Base16Alphabet>::collision_probability(2.0);
+        assert!((p - 1.0 / 65536.0).abs() < 1e-9);
+        // Far more IDs than the keyspace.
+        assert_eq!(Nanoid::<4, Base16Alphabet>::collision_probability(1e6), 1.0);
+
+        // Compare with the exact probability of the birthday problem.
+        let keyspace = Nanoid::<6, Base16Alphabet>::KEYSPACE;
+        let exact = 1.0
+            - (0..1000)
+                .map(|i| 1.0 - i as f64 / keyspace)
+                .product::<f64>();
+        let p = Nanoid::<6, Base16Alphabet>::collision_probability(1000.0);
+        assert!((p - exact).abs() < 1e-4, "{p}, {exact}");
+    }
+
+    #[test]
+    fn test_count_for_collision_probability() {
+        fn inner<const N: usize, A: Alphabet>() {
+            for probability in [1e-9, 0.01, 0.5, 0.99] {
+                let count = Nanoid::<N, A>::count_for_collision_probability(probability);
+                let p = Nanoid::<N, A>::collision_probability(count.round());
+                assert!(
+                    (p / probability - 1.0).abs() < 1e-2,
+                    "{probability}, {count}, {p}"
+                );
+            }
+        }
+
+        inner::<8, Base58Alphabet>();
+        inner::<10, Base62Alphabet>();
+        inner::<16, Base36Alphabet>();
+        inner::<21, Base64UrlAlphabet>();
+    }
+
+    #[test]
+    fn test_length_for_entropy() {
+        fn inner<A: Alphabet>() {
+            let radix = A::VALID_SYMBOL_LIST.len() as f64;
+            for bits in [0, 1, 32, 64, 100, 128, 160, 256, 4096] {
+                let len = length_for_entropy::<A>(bits);
+                let expected = (bits as f64 / radix.log2()).ceil() as usize;
+                assert_eq!(len, expected, "{bits}");
+            }
+        }
+
+        inner::<Base64UrlAlphabet>();
+        inner::<Base62Alphabet>();
+        inner::<Base58Alphabet>();
+        inner::<Base36Alphabet>();
+        inner::<Base32Alphabet>();
+        inner::<Base16Alphabet>();
+
+        // Exact at the boundary of powers of two.
+        assert_eq!(length_for_entropy::<Base16Alphabet>(128), 32);
+        assert_eq!(length_for_entropy::<Base16Alphabet>(129), 33);
+        assert_eq!(length_for_entropy::<Base64UrlAlphabet>(126), 21);
+        assert_eq!(length_for_entropy::<Base64UrlAlphabet>(127), 22);
+    }
+
+    #[test]
+    fn test_assert_min_entropy() {
+        const _: () = Nanoid::<21>::assert_min_entropy(126);
+        const _: () = Nanoid::<22, Base58Alphabet>::assert_min_entropy(128);
+        const _: () = Nanoid::<32, Base16Alphabet>::assert_min_entropy(128);
+        const _: () = Nanoid::<0>::assert_min_entropy(0);
+
+        // A single-symbol alphabet has no entropy, which is enough for zero bits.
+        crate::alphabet::alphabet!(Single = '!');
+        const _: () = Nanoid::<4, Single>::assert_min_entropy(0);
+        assert_eq!(length_for_entropy::<Single>(0), 0);
+        assert!(std::panic::catch_unwind(|| Nanoid::<4, Single>::assert_min_entropy(1)).is_err());
+
+        assert!(std::panic::catch_unwind(|| Nanoid::<21>::assert_min_entropy(127)).is_err());
+        assert!(
+            std::panic::catch_unwind(|| Nanoid::<8, Base16Alphabet>::assert_min_entropy(128))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_time_for_collision_probability() {
+        let count = Nanoid::<8, Base58Alphabet>::count_for_collision_probability(0.01);
+        let time = Nanoid::<8, Base58Alphabet>::time_for_collision_probability(0.01, 10.0);
+        assert!((time.as_secs_f64() - count / 10.0).abs() < 1e-6);
+
+        let time = Nanoid::<200>::time_for_collision_probability(0.01, 1.0);
+        assert_eq!(time, Duration::MAX);
+        let time = Nanoid::<21>::time_for_collision_probability(1.0, 1.0);
+        assert_eq!(time, Duration::MAX);
+        let time = Nanoid::<21>::time_for_collision_probability(0.0, 1.0);
+        assert_eq!(time, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_time_for_collision_probability_rejects_invalid_input() {
+        for (probability, ids_per_second) in [
+            (f64::NAN, 1.0),
+            (-0.01, 1.0),
+            (1.01, 1.0),
+            (0.01, 0.0),
+            (0.01, -1.0),
+            (0.01, f64::NAN),
+        ] {
+            assert!(
+                std::panic::catch_unwind(|| {
+                    Nanoid::<21>::time_for_collision_probability(probability, ids_per_second)
+                })
+                .is_err(),
+                "{probability}, {ids_per_second}"
+            );
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{
+    Nanoid, ParseError,
+    alphabet::{AlphabetExt, Base32CrockfordAlphabet, INVALID_SYMBOL},
+};
+
+/// The symbols for the check values `0..37`. The last five are only used as check symbols.
+const CHECK_SYMBOL_LIST: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+/// Crockford's Base32.
+///
+/// See <https://www.crockford.com/base32.html> for the specification.
+impl<const N: usize> Nanoid<N, Base32CrockfordAlphabet> {
+    /// Parse a string with the decoding rules of Crockford's Base32.
+    ///
+    /// Unlike [`Nanoid::try_from_str`], hyphens are ignored, and the string may be followed by a check symbol.
+    /// Like [`Nanoid::try_from_str`], the symbols are case-insensitive and `I`, `L` and `O` are read as `1`, `1` and `0`.
+    ///
+    /// # Errors
+    ///
+    /// - If the string has neither `N` nor `N + 1` symbols excluding hyphens, this method returns [`ParseError::InvalidLength`].
+    /// - If the string contains a character that is not in the alphabet, this method returns [`ParseError::InvalidCharacter`].
+    /// - If the check symbol does not match, this method returns [`ParseError::InvalidChecksum`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base32CrockfordAlphabet, Nanoid, ParseError};
+    ///
+    /// type Code = Nanoid<8, Base32CrockfordAlphabet>;
+    ///
+    /// let id = Code::try_from_crockford("0000-016J")?;
+    /// assert_eq!(id.as_str(), "0000016J");
+    /// assert_eq!(id.to_u64()?, 1234);
+    ///
+    /// // Case-insensitive, with aliases and a check symbol
+    /// assert_eq!(Code::try_from_crockford("oooo-ol6j-d")?, id);
+    /// assert_eq!(Code::try_from_crockford("0000-016J-E"), Err(ParseError::InvalidChecksum));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn try_from_crockford(s: &str) -> Result<Self, ParseError> {
+        let s = s.as_bytes();
+
+        let mut buf = [0; N];
+        let mut check = None;
+        let mut len = 0;
+        let mut i = 0;
+        while i < s.len() {
+            if s[i] != b'-' {
+                if len < N {
+                    buf[len] = s[i];
+                } else if len == N {
+                    check = Some(s[i]);
+                }
+                len += 1;
+            }
+            i += 1;
+        }
+        if len != N && len != N + 1 {
+            return Err(ParseError::InvalidLength {
+                expected: N,
+                actual: len,
+            });
+        }
+
+        let id = match Self::try_from_bytes(&buf) {
+            Ok(id) => id,
+            Err(e) => return Err(e),
+        };
+        if let Some(check) = check {
+            let value = match check_value(check) {
+                Some(value) => value,
+                None => return Err(ParseError::InvalidCharacter(check)),
+            };
+            if value != id.crockford_check_value() {
+                return Err(ParseError::InvalidChecksum);
+            }
+        }
+
+        Ok(id)
+    }
+
+    /// Get the check symbol of the Nano ID, which is the value of the Nano ID modulo 37.
+    ///
+    /// The check symbol is one of the symbols in the alphabet or `*~$=U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base32CrockfordAlphabet, Nanoid};
+    ///
+    /// let id: Nanoid<8, Base32CrockfordAlphabet> = Nanoid::from_u64(1234)?;
+    /// assert_eq!(id.crockford_check_symbol(), 'D');
+    /// assert_eq!(format!("{id}{}", id.crockford_check_symbol()), "0000016JD");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub const fn crockford_check_symbol(&self) -> char {
+        CHECK_SYMBOL_LIST[self.crockford_check_value() as usize] as char
+    }
+
+    /// Calculate the value of the Nano ID modulo 37.
+    const fn crockford_check_value(&self) -> u8 {
+        let mut remainder = 0;
+        let mut i = 0;
+        while i < N {
+            let digit = Base32CrockfordAlphabet::SYMBOL_INDEX_MAP[self.inner[i] as usize];
+            remainder = (remainder * 32 + digit as u32) % 37;
+            i += 1;
+        }
+        remainder as u8
+    }
+}
+
+/// Get the check value of a check symbol, accepting the aliases of the alphabet and lowercase `u`.
+const fn check_value(symbol: u8) -> Option<u8> {
+    match symbol {
+        b'*' => Some(32),
+        b'~' => Some(33),
+        b'$' => Some(34),
+        b'=' => Some(35),
+        b'U' | b'u' => Some(36),
+        _ if symbol.is_ascii()
+            && Base32CrockfordAlphabet::NORMALIZE_MAP[symbol as usize] != INVALID_SYMBOL =>
+        {
+            let symbol = Base32CrockfordAlphabet::NORMALIZE_MAP[symbol as usize];
+            Some(Base32CrockfordAlphabet::SYMBOL_INDEX_MAP[symbol as usize])
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    type Code = Nanoid<8, Base32CrockfordAlphabet>;
+
+    #[test]
+    fn test_crockford_digits_match_values() {
+        let id = Code::from_u64(0x1234_5678).unwrap();
+        assert_eq!(id.as_str(), "00938NKR");
+        assert_eq!(
+            Code::try_from_crockford("00938NKR").unwrap().to_u64(),
+            Ok(0x1234_5678)
+        );
+        assert_eq!(Code::MAX.to_u64(), Ok((1 << 40) - 1));
+    }
+
+    #[test]
+    fn test_crockford_aliases_and_hyphens() {
+        let id: Code = "0000016J".parse().unwrap();
+        for s in [
+            "0000016J",
+            "0000016j",
+            "OoOo-Ol6J",
+            "0-0-0-0-0-1-6-J",
+            "--0000016J--",
+            "OOOOoi6J",
+            "0000016JD",
+            "0000-016J-d",
+        ] {
+            assert_eq!(Code::try_from_crockford(s), Ok(id), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_crockford_check_symbol() {
+        fn inner(value: u64, expected: char) {
+            let id = Code::from_u64(value).unwrap();
+            assert_eq!(id.crockford_check_symbol(), expected);
+            assert_eq!(Code::try_from_crockford(&format!("{id}{expected}")), Ok(id));
+            assert_eq!(
+                Code::try_from_crockford(&format!("{id}{}", expected.to_ascii_lowercase())),
+                Ok(id)
+            );
+        }
+
+        inner(0, '0');
+        inner(1234, 'D');
+        inner(31, 'Z');
+        inner(32, '*');
+        inner(33, '~');
+        inner(34, '$');
+        inner(35, '=');
+        inner(36, 'U');
+        inner(37, '0');
+    }
+
+    #[test]
+    fn test_crockford_check_symbol_detects_errors() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let id: Code = Nanoid::new_with(&mut rng);
+            let s = format!("{id}{}", id.crockford_check_symbol());
+            for i in 0..8 {
+                let mut typo = s.clone().into_bytes();
+                typo[i] = if typo[i] == b'0' { b'1' } else { b'0' };
+                let typo = String::from_utf8(typo).unwrap();
+                assert_eq!(
+                    Code::try_from_crockford(&typo),
+                    Err(ParseError::InvalidChecksum)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_crockford_parse_errors() {
+        assert_eq!(
+            Code::try_from_crockford("0000-016"),
+            Err(ParseError::InvalidLength {
+                expected: 8,
+                actual: 7
+            })
+        );
+        assert_eq!(
+            Code::try_from_crockford("0000-016J-DD"),
+            Err(ParseError::InvalidLength {
+                expected: 8,
+                actual: 10
+            })
+        );
+        assert_eq!(
+            Code::try_from_crockford("0000U16J"),
+            Err(ParseError::InvalidCharacter(b'U'))
+        );
+        assert_eq!(
+            Code::try_from_crockford("0000016J#"),
+            Err(ParseError::InvalidCharacter(b'#'))
+        );
+        assert_eq!(
+            Code::try_from_crockford("0000016JE"),
+            Err(ParseError::InvalidChecksum)
+        );
+        assert_eq!(
+            "0000-016J".parse::<Code>(),
+            Err(ParseError::InvalidLength {
+                expected: 8,
+                actual: 9
+            })
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    Nanoid,
+    alphabet::{Alphabet, AlphabetExt},
+};
+
+impl<const N: usize, A: Alphabet> Nanoid<N, A> {
+    /// Generate a new Nano ID from the provided source of random bytes, using the same algorithm as
+    /// `customRandom` of the original JavaScript library.
+    ///
+    /// Given the same random bytes, this function produces exactly the same ID as
+    /// `customRandom(alphabet, N, getRandom)()` in JavaScript, where `alphabet` is [`Alphabet::SYMBOL_LIST`]
+    /// in the same order. This is useful to reproduce IDs generated by a JavaScript application from a seeded source.
+    ///
+    /// `get_random` is called with a buffer to fill with random bytes, just like `getRandom` in JavaScript.
+    /// The buffer length is the `step` of the JavaScript implementation, and the function may be called
+    /// several times since bytes that exceed the alphabet size are rejected.
+    ///
+    /// Prefer [`Nanoid::new`] or [`Nanoid::new_with`] unless you need compatibility with the JavaScript library.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the provided [`Alphabet`] produces non-ascii characters, but this
+    /// never happens unless the alphabet is implemented incorrectly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    /// use rand::Rng;
+    ///
+    /// let mut rng = rand::rng();
+    /// let id: Nanoid = Nanoid::new_with_custom_random(|buf| rng.fill_bytes(buf));
+    /// ```
+    #[must_use]
+    pub fn new_with_custom_random(mut get_random: impl FnMut(&mut [u8])) -> Self {
+        let mut inner = [0; N];
+        if N == 0 {
+            return Self::from_inner(inner);
+        }
+
+        let len = A::VALID_SYMBOL_LIST.len();
+        // `(2 << (31 - Math.clz32((alphabet.length - 1) | 1))) - 1`
+        let mask = (2 << ((len - 1) | 1).ilog2()) - 1;
+        // `Math.ceil((1.6 * mask * defaultSize) / alphabet.length)`
+        let step = ((1.6 * mask as f64 * N as f64) / len as f64).ceil() as usize;
+
+        let mut bytes = vec![0; step];
+        let mut filled = 0;
+        loop {
+            get_random(&mut bytes);
+            for &byte in bytes.iter().rev() {
+                if let Some(&symbol) = A::VALID_SYMBOL_LIST.get(usize::from(byte) & mask) {
+                    inner[filled] = symbol;
+                    filled += 1;
+                    if filled == N {
+                        return Self::from_inner(inner);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::alphabet::{
+        Base16Alphabet, Base36Alphabet, Base58Alphabet, Base62Alphabet, Base64UrlAlphabet,
+    };
+
+    /// `urlAlphabet` of the JavaScript library, which is used by its `nanoid` function.
+    struct UrlAlphabet;
+
+    impl Alphabet for UrlAlphabet {
+        const SYMBOL_LIST: &'static [u8] =
+            b"useandom-26T198340PX75pxJACKVERYMINDBUSHWOLF_GQZbfghjklqvwyzrict";
+    }
+
+    /// A linear congruential generator that is easy to reproduce in JavaScript:
+    ///
+    /// ```js
+    /// function lcg(seed) {
+    ///   let state = seed >>> 0
+    ///   return n => {
+    ///     let a = new Uint8Array(n)
+    ///     for (let i = 0; i < n; i++) {
+    ///       state = (Math.imul(state, 1103515245) + 12345) >>> 0
+    ///       a[i] = state >>> 24
+    ///     }
+    ///     return a
+    ///   }
+    /// }
+    /// ```
+    fn lcg(seed: u32) -> impl FnMut(&mut [u8]) {
+        let mut state = seed;
+        move |buf| {
+            for byte in buf {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                *byte = (state >> 24) as u8;
+            }
+        }
+    }
+
+    #[test]
+    fn test_new_with_custom_random_js_vectors() {
+        // Generated by calling `customRandom(alphabet, size, lcg(seed))` of the JavaScript library twice.
+        fn inner<const N: usize, A: Alphabet>(seed: u32, expected: [&str; 2]) {
+            let mut get_random = lcg(seed);
+            let id1: Nanoid<N, A> = Nanoid::new_with_custom_random(&mut get_random);
+            let id2: Nanoid<N, A> = Nanoid::new_with_custom_random(&mut get_random);
+            assert_eq!([id1.as_str(), id2.as_str()], expected);
+        }
+
+        inner::<21, Base64UrlAlphabet>(0, ["MFkCWea5q9bafQE7m_lSm", "w_GyZKJmTvHOvCPqyzZGN"]);
+        inner::<21, Base64UrlAlphabet>(42, ["qa59zSmIE0s0Q1VXqUO1f", "CjIM1C8NscXqxF3h0PRgh"]);
+        inner::<21, Base62Alphabet>(0, ["fMFkCWea5q9bafQE7mlSm", "6MwGyZKJmTvHOvCPqyzZG"]);
+        inner::<21, Base62Alphabet>(42, ["jqa59zSmIE0s0Q1VXqUO1", "73CjIM1C8NscXqxF3h0PR"]);
+        inner::<21, Base58Alphabet>(0, ["BHhNFnCYgc9tdchSEpoUp", "9RzqNzG2bLKpVyHQyCRt2"]);
+        inner::<21, Base58Alphabet>(42, ["eRmtc93UpJE4v4S5XZtWQ", "nCC7CmJN5CPveZt1F7j4R"]);
+        inner::<6, Base64UrlAlphabet>(0, ["gvN_CN", "E7m_lS"]);
+        inner::<6, Base64UrlAlphabet>(42, ["bOn1E2", "VXqUO1"]);
+        inner::<10, Base62Alphabet>(0, ["lSmWpvgvNC", "MFkCWea5q9"]);
+        inner::<10, Base62Alphabet>(42, ["UO1fHCXbOn", "qa59zSmIE0"]);
+        inner::<12, Base58Alphabet>(0, ["SEpoUpYsyiyP", "qdUZ2BHhNFnC"]);
+        inner::<12, Base58Alphabet>(42, ["5XZtWQ5hHCZd", "Mh6UeRmtc93U"]);
+        inner::<16, Base36Alphabet>(0, ["1SXBH5MFCW40105Q", "UDGZX25UCIPMGZKJ"]);
+        inner::<16, Base36Alphabet>(42, ["ML5S2P90SIEQVXUO", "VMPTAFLW06CCC9IM"]);
+        inner::<10, Base16Alphabet>(0, ["C0039A978C", "084347549A"]);
+        inner::<10, Base16Alphabet>(42, ["F91C1581FE", "DC02EE6EAF"]);
+        inner::<21, UrlAlphabet>(0, ["1dBepRCwLiKCY4nzScUPS", "bcogA62SXZm8Ze3LghAo9"]);
+        inner::<21, UrlAlphabet>(42, ["LCwihPS-nj_j4k5xL78kY", "eD-1ker9_VxLfdqIj30MI"]);
+    }
+
+    #[test]
+    fn test_new_with_custom_random_step() {
+        fn inner<const N: usize, A: Alphabet>(expected_step: usize) {
+            let _: Nanoid<N, A> = Nanoid::new_with_custom_random(|buf| {
+                assert_eq!(buf.len(), expected_step);
+                buf.fill(0);
+            });
+        }
+
+        inner::<21, Base64UrlAlphabet>(34);
+        inner::<21, Base62Alphabet>(35);
+        inner::<21, Base58Alphabet>(37);
+        inner::<10, Base16Alphabet>(15);
+    }
+
+    #[test]
+    fn test_new_with_custom_random_empty() {
+        let id: Nanoid<0> = Nanoid::new_with_custom_random(|_| unreachable!());
+        assert_eq!(id.as_str(), "");
+    }
+}
@@ -0,0 +1,405 @@
+use std::marker::PhantomData;
+
+use crate::{
+    Nanoid, ParseError,
+    alphabet::{Alphabet, AlphabetExt, Base64UrlAlphabet, INVALID_SYMBOL},
+};
+
+/// A Nano ID whose length is chosen at runtime.
+///
+/// This is a heap-allocated companion of [`Nanoid`] for lengths that are only known at runtime, such as lengths
+/// loaded from configuration. Apart from the length, it behaves like a [`Nanoid`]: the same symbols are generated
+/// from the same random number generator, and parsing, comparison and hashing work the same way.
+///
+/// # Generic parameters
+///
+/// - `A`: The alphabet used in the Nano ID. The default is [`Base64UrlAlphabet`].
+///
+/// # Examples
+///
+/// ```
+/// use nid::{DynNanoid, Nanoid};
+///
+/// let len = 12;
+///
+/// // Generate a new Nano ID with the length.
+/// let id: DynNanoid = DynNanoid::new(len);
+/// assert_eq!(id.len(), 12);
+///
+/// // Parse a string into a Nano ID, checking the length.
+/// let id: DynNanoid = DynNanoid::try_from_str_with_len("kP_IH1DPMx3X", len)?;
+/// assert_eq!(id.as_str(), "kP_IH1DPMx3X");
+///
+/// // Convert from and to a fixed-length Nano ID.
+/// let fixed: Nanoid<12> = id.clone().try_into()?;
+/// assert_eq!(DynNanoid::from(fixed), id);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
+pub struct DynNanoid<A: Alphabet = Base64UrlAlphabet> {
+    /// The Nano ID string. All characters are ASCII.
+    inner: Box<[u8]>,
+
+    _marker: PhantomData<fn() -> A>,
+}
+
+impl<A: Alphabet> DynNanoid<A> {
+    /// Generate a new Nano ID of `len` symbols using random number generator seeded by the system.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::DynNanoid;
+    /// let id: DynNanoid = DynNanoid::new(21);
+    /// ```
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        let id = Self::new_with(len, crate::system_rng());
+        crate::track_generated_str::<A>(id.as_str());
+        id
+    }
+
+    /// Generate a new Nano ID of `len` symbols using the provided random number generator.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::DynNanoid;
+    /// let id: DynNanoid = DynNanoid::new_with(21, rand::rng());
+    /// ```
+    #[must_use]
+    pub fn new_with(len: usize, rng: impl rand::Rng) -> Self {
+        let mut inner = vec![0; len].into_boxed_slice();
+        crate::fill_random_symbols(A::VALID_SYMBOL_LIST, rng, &mut inner);
+        Self::from_inner(inner)
+    }
+
+    /// Parse a string of any length into a [`DynNanoid`].
+    ///
+    /// Use [`DynNanoid::try_from_str_with_len`] to check the length as well.
+    ///
+    /// # Errors
+    ///
+    /// If the string contains a character that is not in the alphabet, this method returns [`ParseError::InvalidCharacter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::DynNanoid;
+    /// let id: DynNanoid = DynNanoid::try_from_str("kP_IH1DPMx")?;
+    /// assert_eq!(id.len(), 10);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_from_str(s: &str) -> Result<Self, ParseError> {
+        Self::try_from_slice(s.as_bytes())
+    }
+
+    /// Parse a string into a [`DynNanoid`] of `len` symbols.
+    ///
+    /// # Errors
+    ///
+    /// - If the length of the string is not equal to `len`, this method returns [`ParseError::InvalidLength`].
+    /// - If the string contains a character that is not in the alphabet, this method returns [`ParseError::InvalidCharacter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{DynNanoid, ParseError};
+    ///
+    /// let result = DynNanoid::<nid::alphabet::Base64UrlAlphabet>::try_from_str_with_len("kP_IH1DPMx", 12);
+    /// assert_eq!(result, Err(ParseError::InvalidLength { expected: 12, actual: 10 }));
+    /// ```
+    pub fn try_from_str_with_len(s: &str, len: usize) -> Result<Self, ParseError> {
+        if s.len() != len {
+            return Err(ParseError::InvalidLength {
+                expected: len,
+                actual: s.len(),
+            });
+        }
+        Self::try_from_str(s)
+    }
+
+    /// Parse a byte slice into a [`DynNanoid`], replacing aliases like [`Nanoid::try_from_bytes`].
+    fn try_from_slice(s: &[u8]) -> Result<Self, ParseError> {
+        let inner = s
+            .iter()
+            .map(|&symbol| match A::NORMALIZE_MAP.get(usize::from(symbol)) {
+                Some(&normalized) if normalized != INVALID_SYMBOL => Ok(normalized),
+                _ => Err(ParseError::InvalidCharacter(symbol)),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self::from_inner(inner))
+    }
+
+    /// Get the string representation of the [`DynNanoid`].
+    #[must_use]
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: all characters are ASCII.
+        unsafe { std::str::from_utf8_unchecked(&self.inner) }
+    }
+
+    /// Get the number of symbols in the [`DynNanoid`].
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Return `true` if the [`DynNanoid`] has no symbols.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Create a [`DynNanoid`] without validation. All elements must be symbols of the alphabet.
+    fn from_inner(inner: Box<[u8]>) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+// `Clone` cannot be derived due to a limitation of the compiler.
+// https://github.com/rust-lang/rust/issues/26925
+impl<A: Alphabet> Clone for DynNanoid<A> {
+    fn clone(&self) -> Self {
+        Self::from_inner(self.inner.clone())
+    }
+}
+
+// `PartialEq` cannot be derived as well.
+impl<A: Alphabet> PartialEq for DynNanoid<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+// `Eq` cannot be derived as well.
+impl<A: Alphabet> Eq for DynNanoid<A> {}
+
+// `Hash` cannot be derived as well.
+impl<A: Alphabet> std::hash::Hash for DynNanoid<A> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+// `PartialOrd` cannot be derived as well.
+impl<A: Alphabet> PartialOrd for DynNanoid<A> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// `Ord` cannot be derived as well.
+impl<A: Alphabet> Ord for DynNanoid<A> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<A: Alphabet> std::fmt::Debug for DynNanoid<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DynNanoid").field(&self.as_str()).finish()
+    }
+}
+
+impl<A: Alphabet> std::fmt::Display for DynNanoid<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<A: Alphabet> From<DynNanoid<A>> for String {
+    fn from(id: DynNanoid<A>) -> Self {
+        id.as_str().to_owned()
+    }
+}
+
+impl<A: Alphabet> AsRef<str> for DynNanoid<A> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<A: Alphabet> TryFrom<String> for DynNanoid<A> {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from_str(&s)
+    }
+}
+
+impl<A: Alphabet> std::str::FromStr for DynNanoid<A> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const N: usize, A: Alphabet> From<Nanoid<N, A>> for DynNanoid<A> {
+    fn from(id: Nanoid<N, A>) -> Self {
+        Self::from_inner(id.inner.into())
+    }
+}
+
+impl<const N: usize, A: Alphabet> TryFrom<DynNanoid<A>> for Nanoid<N, A> {
+    type Error = ParseError;
+
+    /// Convert a [`DynNanoid`] into a [`Nanoid`] of `N` symbols.
+    ///
+    /// If the length is not equal to `N`, this returns [`ParseError::InvalidLength`].
+    fn try_from(id: DynNanoid<A>) -> Result<Self, Self::Error> {
+        match <[u8; N]>::try_from(&*id.inner) {
+            Ok(inner) => Ok(Nanoid::from_inner(inner)),
+            Err(_) => Err(ParseError::InvalidLength {
+                expected: N,
+                actual: id.len(),
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A: Alphabet> serde::Serialize for DynNanoid<A> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A: Alphabet> serde::Deserialize<'de> for DynNanoid<A> {
+    fn deserialize<D>(deserializer: D) -> Result<DynNanoid<A>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::try_from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::alphabet::{Base16Alphabet, Base32Alphabet, Base58Alphabet, Base62Alphabet};
+
+    #[test]
+    fn test_dyn_matches_fixed() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let mut rng1 = rand::rngs::StdRng::seed_from_u64(0);
+            let mut rng2 = rand::rngs::StdRng::seed_from_u64(0);
+            for _ in 0..100 {
+                let fixed: Nanoid<N, A> = Nanoid::new_with(&mut rng1);
+                let id: DynNanoid<A> = DynNanoid::new_with(N, &mut rng2);
+                assert_eq!(id.as_str(), fixed.as_str());
+                assert_eq!(DynNanoid::from(fixed), id);
+                assert_eq!(Nanoid::try_from(id), Ok(fixed));
+            }
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<21, Base62Alphabet>();
+        inner::<12, Base58Alphabet>();
+        inner::<7, Base16Alphabet>();
+        inner::<0, Base62Alphabet>();
+    }
+
+    #[test]
+    fn test_dyn_parse() {
+        let id: DynNanoid = "kP_IH1DPMx".parse().unwrap();
+        assert_eq!(id.as_str(), "kP_IH1DPMx");
+        assert_eq!(id.len(), 10);
+        assert!(!id.is_empty());
+        assert_eq!(
+            DynNanoid::try_from("kP_IH1DPMx".to_string()),
+            Ok(id.clone())
+        );
+        assert_eq!(DynNanoid::try_from_str_with_len("kP_IH1DPMx", 10), Ok(id));
+
+        let id: DynNanoid = "".parse().unwrap();
+        assert!(id.is_empty());
+
+        let id: DynNanoid<Base32Alphabet> = "abc234".parse().unwrap();
+        assert_eq!(id.as_str(), "ABC234");
+    }
+
+    #[test]
+    fn test_dyn_parse_invalid() {
+        assert_eq!(
+            "kP_IH1DPM#".parse::<DynNanoid>(),
+            Err(ParseError::InvalidCharacter(b'#'))
+        );
+        assert_eq!(
+            "アイ".parse::<DynNanoid>(),
+            Err(ParseError::InvalidCharacter(0xe3))
+        );
+        assert_eq!(
+            DynNanoid::<Base64UrlAlphabet>::try_from_str_with_len("kP_IH1DPMx", 21),
+            Err(ParseError::InvalidLength {
+                expected: 21,
+                actual: 10
+            })
+        );
+        assert_eq!(
+            Nanoid::<21>::try_from("kP_IH1DPMx".parse::<DynNanoid>().unwrap()),
+            Err(ParseError::InvalidLength {
+                expected: 21,
+                actual: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_dyn_cmp_and_hash() {
+        let ids: Vec<DynNanoid> = ["abc", "abcd", "abd", "b"]
+            .into_iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        assert!(ids.is_sorted());
+
+        let set: HashSet<DynNanoid> = ids.iter().cloned().collect();
+        assert!(set.contains(&"abcd".parse().unwrap()));
+        assert_eq!(set.len(), 4);
+    }
+
+    #[test]
+    fn test_dyn_format() {
+        let id: DynNanoid = "kP_IH1DPMx".parse().unwrap();
+        assert_eq!(id.to_string(), "kP_IH1DPMx");
+        assert_eq!(id.as_ref(), "kP_IH1DPMx");
+        assert_eq!(format!("{id:?}"), "DynNanoid(\"kP_IH1DPMx\")");
+        assert_eq!(String::from(id), "kP_IH1DPMx");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_dyn_serde() {
+        let id: DynNanoid = "kP_IH1DPMx".parse().unwrap();
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"kP_IH1DPMx\"");
+        assert_eq!(
+            serde_json::from_str::<DynNanoid>("\"kP_IH1DPMx\"").unwrap(),
+            id
+        );
+        assert!(serde_json::from_str::<DynNanoid>("\"kP_IH1DPM#\"").is_err());
+    }
+}
@@ -0,0 +1,404 @@
+use crate::{
+    Nanoid, OverflowError,
+    alphabet::{Alphabet, AlphabetExt},
+};
+
+/// Integer encoding.
+///
+/// An integer is encoded in base `alphabet size`, left-padded with the first symbol to fill all `N` symbols.
+/// The digit value of a symbol is its position in [`Alphabet::SYMBOL_LIST`], so `0` is encoded as the first symbol,
+/// `1` as the second symbol, and so on.
+///
+/// # Ordering
+///
+/// The [`Ord`] implementation of [`Nanoid`] and the order of the string representations follow the byte order of the
+/// symbols, not the numeric order of the integers. The two orders agree only if [`Alphabet::SYMBOL_LIST`] is sorted in
+/// byte order, which is not the case for most of the built-in alphabets.
+/// Wrap Nano IDs in [`ByValue`] to sort them by value, or compare them with [`Nanoid::cmp_by_value`].
+impl<const N: usize, A: Alphabet> Nanoid<N, A> {
+    /// Encode a `u64` into a Nano ID.
+    ///
+    /// # Ordering
+    ///
+    /// The encoded Nano IDs are **not** sorted by value with [`Ord`], which compares the symbols in byte order.
+    /// Use [`ByValue`] or [`Nanoid::cmp_by_value`] to sort them by value.
+    ///
+    /// ```
+    /// use nid::{alphabet::Base62Alphabet, ByValue, Nanoid};
+    ///
+    /// let a: Nanoid<4, Base62Alphabet> = Nanoid::from_u64(51)?;
+    /// let b: Nanoid<4, Base62Alphabet> = Nanoid::from_u64(52)?;
+    /// assert_eq!((a.as_str(), b.as_str()), ("AAAz", "AAA0"));
+    /// assert!(a > b);
+    /// assert!(ByValue(a) < ByValue(b));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the value does not fit in `N` symbols, this method returns [`OverflowError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base62Alphabet, Nanoid};
+    ///
+    /// let id: Nanoid<8, Base62Alphabet> = Nanoid::from_u64(12345)?;
+    /// assert_eq!(id.as_str(), "AAAAADNH");
+    /// assert_eq!(id.to_u64()?, 12345);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn from_u64(value: u64) -> Result<Self, OverflowError> {
+        Self::from_u128(value as u128)
+    }
+
+    /// Encode a `u128` into a Nano ID.
+    ///
+    /// # Ordering
+    ///
+    /// The encoded Nano IDs are **not** sorted by value with [`Ord`]. See [`Nanoid::from_u64`].
+    ///
+    /// # Errors
+    ///
+    /// If the value does not fit in `N` symbols, this method returns [`OverflowError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base36Alphabet, Nanoid, OverflowError};
+    ///
+    /// let id: Nanoid<25, Base36Alphabet> = Nanoid::from_u128(u128::MAX)?;
+    /// assert_eq!(id.as_str(), "PFV77B99FZXY18X0QVR9W2ZDD");
+    ///
+    /// let result = Nanoid::<4, Base36Alphabet>::from_u128(36 * 36 * 36 * 36);
+    /// assert_eq!(result, Err(OverflowError));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn from_u128(mut value: u128) -> Result<Self, OverflowError> {
+        let radix = A::VALID_SYMBOL_LIST.len() as u128;
+
+        let mut inner = [A::VALID_SYMBOL_LIST[0]; N];
+        let mut i = N;
+        while i > 0 && value > 0 {
+            i -= 1;
+            inner[i] = A::VALID_SYMBOL_LIST[(value % radix) as usize];
+            value /= radix;
+        }
+
+        if value > 0 {
+            return Err(OverflowError);
+        }
+        Ok(Self::from_inner(inner))
+    }
+
+    /// Decode a Nano ID into a `u64`.
+    ///
+    /// # Errors
+    ///
+    /// If the value does not fit in `u64`, this method returns [`OverflowError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base62Alphabet, Nanoid, OverflowError};
+    ///
+    /// let id: Nanoid<8, Base62Alphabet> = "AAAAADNH".parse()?;
+    /// assert_eq!(id.to_u64()?, 12345);
+    ///
+    /// let id: Nanoid<21, Base62Alphabet> = "999999999999999999999".parse()?;
+    /// assert_eq!(id.to_u64(), Err(OverflowError));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn to_u64(&self) -> Result<u64, OverflowError> {
+        match self.to_u128() {
+            Ok(value) if value <= u64::MAX as u128 => Ok(value as u64),
+            _ => Err(OverflowError),
+        }
+    }
+
+    /// Decode a Nano ID into a `u128`.
+    ///
+    /// # Errors
+    ///
+    /// If the value does not fit in `u128`, this method returns [`OverflowError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base36Alphabet, Nanoid};
+    ///
+    /// let id: Nanoid<25, Base36Alphabet> = "AAAAAAAAAAAAAAAAAAAAAABA9".parse()?;
+    /// assert_eq!(id.to_u128()?, 36 * 36 + 35);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn to_u128(&self) -> Result<u128, OverflowError> {
+        let radix = A::VALID_SYMBOL_LIST.len() as u128;
+
+        let mut value: u128 = 0;
+        let mut i = 0;
+        while i < N {
+            let digit = A::SYMBOL_INDEX_MAP[self.inner[i] as usize] as u128;
+            value = match value.checked_mul(radix) {
+                Some(value) => match value.checked_add(digit) {
+                    Some(value) => value,
+                    None => return Err(OverflowError),
+                },
+                None => return Err(OverflowError),
+            };
+            i += 1;
+        }
+        Ok(value)
+    }
+
+    /// Compare two Nano IDs by their values, as returned by [`Nanoid::to_u128`].
+    ///
+    /// Unlike [`Ord`], which compares the symbols in byte order, this compares the symbols in the order of
+    /// [`Alphabet::SYMBOL_LIST`]. The comparison never overflows, even if the values don't fit in `u128`.
+    /// [`ByValue`] implements [`Ord`] with this comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base62Alphabet, Nanoid};
+    ///
+    /// let a: Nanoid<4, Base62Alphabet> = Nanoid::from_u64(51)?;
+    /// let b: Nanoid<4, Base62Alphabet> = Nanoid::from_u64(52)?;
+    /// assert_eq!((a.as_str(), b.as_str()), ("AAAz", "AAA0"));
+    ///
+    /// // `a` is greater than `b` in byte order, but less than `b` in value.
+    /// assert!(a > b);
+    /// assert!(a.cmp_by_value(&b).is_lt());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub const fn cmp_by_value(&self, other: &Self) -> std::cmp::Ordering {
+        let mut i = 0;
+        while i < N {
+            let a = A::SYMBOL_INDEX_MAP[self.inner[i] as usize];
+            let b = A::SYMBOL_INDEX_MAP[other.inner[i] as usize];
+            if a != b {
+                return if a < b {
+                    std::cmp::Ordering::Less
+                } else {
+                    std::cmp::Ordering::Greater
+                };
+            }
+            i += 1;
+        }
+        std::cmp::Ordering::Equal
+    }
+}
+
+/// A Nano ID ordered by its value, as returned by [`Nanoid::to_u128`].
+///
+/// The [`Ord`] implementation of [`Nanoid`] compares the symbols in byte order, which differs from the order of the
+/// values encoded by [`Nanoid::from_u128`], [`Nanoid::from_byte_array`], and [`Nanoid::reencode`] for most alphabets.
+/// Wrap Nano IDs in this type to sort them by value, for example as keys of a [`BTreeMap`](std::collections::BTreeMap).
+///
+/// # Examples
+///
+/// ```
+/// use nid::{alphabet::Base62Alphabet, ByValue, Nanoid};
+///
+/// let mut ids: Vec<Nanoid<4, Base62Alphabet>> =
+///     [52, 51, 0].into_iter().map(Nanoid::from_u64).collect::<Result<_, _>>()?;
+///
+/// ids.sort();
+/// assert_eq!(ids.iter().map(|id| id.to_u64().unwrap()).collect::<Vec<_>>(), [52, 0, 51]);
+///
+/// ids.sort_by_key(|id| ByValue(*id));
+/// assert_eq!(ids.iter().map(|id| id.to_u64().unwrap()).collect::<Vec<_>>(), [0, 51, 52]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct ByValue<const N: usize, A: Alphabet>(pub Nanoid<N, A>);
+
+// `Copy` cannot be derived due to a limitation of the compiler.
+impl<const N: usize, A: Alphabet> Copy for ByValue<N, A> {}
+
+// `Clone` cannot be derived as well.
+impl<const N: usize, A: Alphabet> Clone for ByValue<N, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// `PartialEq` cannot be derived as well.
+impl<const N: usize, A: Alphabet> PartialEq for ByValue<N, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+// `Eq` cannot be derived as well.
+impl<const N: usize, A: Alphabet> Eq for ByValue<N, A> {}
+
+// `Hash` cannot be derived as well.
+impl<const N: usize, A: Alphabet> std::hash::Hash for ByValue<N, A> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<const N: usize, A: Alphabet> PartialOrd for ByValue<N, A> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize, A: Alphabet> Ord for ByValue<N, A> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp_by_value(&other.0)
+    }
+}
+
+impl<const N: usize, A: Alphabet> std::fmt::Debug for ByValue<N, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ByValue").field(&self.0).finish()
+    }
+}
+
+impl<const N: usize, A: Alphabet> From<Nanoid<N, A>> for ByValue<N, A> {
+    fn from(id: Nanoid<N, A>) -> Self {
+        Self(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::alphabet::{
+        Base16Alphabet, Base32Alphabet, Base36Alphabet, Base58Alphabet, Base62Alphabet,
+        Base64UrlAlphabet,
+    };
+
+    #[test]
+    fn test_integer_encoding() {
+        assert_eq!(
+            Nanoid::<8, Base62Alphabet>::from_u64(0).unwrap().as_str(),
+            "AAAAAAAA"
+        );
+        assert_eq!(
+            Nanoid::<8, Base62Alphabet>::from_u64(61).unwrap().as_str(),
+            "AAAAAAA9"
+        );
+        assert_eq!(
+            Nanoid::<8, Base62Alphabet>::from_u64(62).unwrap().as_str(),
+            "AAAAAABA"
+        );
+        assert_eq!(
+            Nanoid::<11, Base64UrlAlphabet>::from_u64(u64::MAX)
+                .unwrap()
+                .as_str(),
+            "P----------"
+        );
+        assert_eq!(
+            Nanoid::<4, Base16Alphabet>::from_u64(0xFFFF)
+                .unwrap()
+                .as_str(),
+            "9999"
+        );
+    }
+
+    #[test]
+    fn test_integer_encoding_follows_symbol_list() {
+        fn inner<A: Alphabet>() {
+            for (i, &symbol) in A::SYMBOL_LIST.iter().enumerate() {
+                let id: Nanoid<1, A> = Nanoid::from_u64(i as u64).unwrap();
+                assert_eq!(id.as_str().as_bytes(), [symbol]);
+            }
+        }
+
+        inner::<Base64UrlAlphabet>();
+        inner::<Base62Alphabet>();
+        inner::<Base58Alphabet>();
+        inner::<Base36Alphabet>();
+        inner::<Base32Alphabet>();
+        inner::<Base16Alphabet>();
+    }
+
+    #[test]
+    fn test_integer_overflow() {
+        assert_eq!(
+            Nanoid::<4, Base16Alphabet>::from_u64(0x10000),
+            Err(OverflowError)
+        );
+        assert_eq!(
+            Nanoid::<10, Base64UrlAlphabet>::from_u64(u64::MAX),
+            Err(OverflowError)
+        );
+        assert_eq!(Nanoid::<0, Base16Alphabet>::from_u64(1), Err(OverflowError));
+        assert_eq!(
+            Nanoid::<0, Base16Alphabet>::from_u64(0).unwrap().to_u64(),
+            Ok(0)
+        );
+
+        let max = |len| "9".repeat(len);
+        assert_eq!(
+            Nanoid::<17, Base16Alphabet>::try_from_str(&max(17))
+                .unwrap()
+                .to_u64(),
+            Err(OverflowError)
+        );
+        assert_eq!(
+            Nanoid::<16, Base16Alphabet>::try_from_str(&max(16))
+                .unwrap()
+                .to_u64(),
+            Ok(u64::MAX)
+        );
+        assert_eq!(
+            Nanoid::<33, Base16Alphabet>::try_from_str(&max(33))
+                .unwrap()
+                .to_u128(),
+            Err(OverflowError)
+        );
+        assert_eq!(
+            Nanoid::<32, Base16Alphabet>::try_from_str(&max(32))
+                .unwrap()
+                .to_u128(),
+            Ok(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn test_integer_roundtrip_and_order() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let values = [
+                0,
+                1,
+                2,
+                61,
+                62,
+                63,
+                12345,
+                u64::MAX as u128 - 1,
+                u64::MAX as u128,
+                u64::MAX as u128 + 1,
+                u128::MAX - 1,
+                u128::MAX,
+            ];
+
+            let mut prev: Option<Nanoid<N, A>> = None;
+            for value in values {
+                let id: Nanoid<N, A> = Nanoid::from_u128(value).unwrap();
+                assert_eq!(id.to_u128(), Ok(value));
+                assert_eq!(id.to_u64(), u64::try_from(value).map_err(|_| OverflowError));
+                if let Some(prev) = prev {
+                    assert_eq!(prev.cmp_by_value(&id), std::cmp::Ordering::Less);
+                    assert_eq!(id.cmp_by_value(&prev), std::cmp::Ordering::Greater);
+                    assert!(ByValue(prev) < ByValue(id));
+                }
+                assert_eq!(id.cmp_by_value(&id), std::cmp::Ordering::Equal);
+                prev = Some(id);
+            }
+        }
+
+        inner::<22, Base64UrlAlphabet>();
+        inner::<22, Base62Alphabet>();
+        inner::<22, Base58Alphabet>();
+        inner::<25, Base36Alphabet>();
+        inner::<26, Base32Alphabet>();
+        inner::<32, Base16Alphabet>();
+    }
+}
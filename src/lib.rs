@@ -81,6 +81,11 @@
 //!
 //! - `serde`: Add support for serialization and deserialization of [`Nanoid`]. Implement [`serde::Serialize`] and [`serde::Deserialize`] for [`Nanoid`].
 //! - `zeroize`: Add support for zeroizing the memory of [`Nanoid`]. Implement [`zeroize::Zeroize`] for [`Nanoid`].
+//! - `pool`: Use a thread-local pool of random bytes in [`Nanoid::new`], which is refilled from the operating system's random number generator in large blocks. The pool is fork-safe.
+//! - `sha2`: Add [`Nanoid::from_name`] to generate a Nano ID deterministically from a namespace and a name using SHA-256.
+//! - `hmac`: Add [`Nanoid::from_key`] and [`PseudonymKeyring`] to derive pseudonymous Nano IDs from sensitive values with a secret key using HMAC-SHA-256, and [`IdCipher`] to encrypt Nano IDs and sequential numbers into Nano IDs of the same shape. This also enables `sha2`.
+//! - `uuid`: Add support for interoperability with [`uuid::Uuid`]. Implement lossless conversions between [`uuid::Uuid`] and 22-symbol [`Nanoid`]s, and add [`Nanoid::new_uuid_v4`] and [`Nanoid::new_uuid_v7`] to generate Nano IDs with the bit layout of UUIDs.
+//! - `track-duplicates`: Add [`enable_duplicate_tracking`] to panic when the same Nano ID is generated twice in the process. Tracking only happens in builds with debug assertions, and is intended for test suites.
 //!
 //! # Comparison with other implementations of Nano ID
 //!
@@ -93,12 +98,63 @@
 #![deny(missing_debug_implementations, missing_docs)]
 
 pub mod alphabet;
+mod bits;
+mod bulk;
+mod bytes;
+mod checksum;
+#[cfg(feature = "hmac")]
+mod cipher;
+mod collision;
+mod crockford;
+mod custom_random;
+mod dynamic;
+mod integer;
+mod monotonic;
+#[cfg(feature = "sha2")]
+mod name;
+#[cfg(feature = "pool")]
+mod pool;
+mod prefix;
+#[cfg(feature = "hmac")]
+mod pseudonym;
+mod reencode;
+mod runtime;
+mod timestamp;
+#[cfg(feature = "track-duplicates")]
+mod track;
+mod unicode;
+mod unique;
+#[cfg(feature = "uuid")]
+mod uuid_interop;
 
 use std::marker::PhantomData;
 
-use alphabet::{Alphabet, AlphabetExt, Base64UrlAlphabet};
+use alphabet::{Alphabet, AlphabetExt, Base64UrlAlphabet, INVALID_SYMBOL};
 use rand::RngExt;
 
+#[cfg(feature = "serde")]
+pub use crate::runtime::RuntimeNanoidSeed;
+#[cfg(feature = "track-duplicates")]
+pub use crate::track::enable_duplicate_tracking;
+pub use crate::{
+    bytes::length_for_bytes,
+    checksum::ChecksummedNanoid,
+    collision::length_for_entropy,
+    dynamic::DynNanoid,
+    integer::ByValue,
+    monotonic::MonotonicGenerator,
+    prefix::{Prefix, PrefixedNanoid},
+    reencode::length_for_reencode,
+    runtime::{RuntimeAlphabet, RuntimeNanoid},
+    unicode::UnicodeNanoid,
+    unique::IdSet,
+};
+#[cfg(feature = "hmac")]
+pub use crate::{
+    cipher::IdCipher,
+    pseudonym::{PseudonymKey, PseudonymKeyring},
+};
+
 /// A Nano ID.
 ///
 /// # Generic parameters
@@ -183,9 +239,12 @@ pub struct Nanoid<const N: usize = 21, A: Alphabet = Base64UrlAlphabet> {
 
 /// An error that can occur when parsing a string into a Nano ID.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error)]
+#[non_exhaustive]
 pub enum ParseError {
     /// The length of the provided value is not equal to the expected length.
-    #[error("Invalid length: expected {expected} bytes, but got {actual} bytes")]
+    ///
+    /// The length is the number of bytes, except for [`UnicodeNanoid`] where it is the number of characters.
+    #[error("Invalid length: expected {expected}, but got {actual}")]
     InvalidLength {
         /// The expected length.
         expected: usize,
@@ -196,9 +255,84 @@ pub enum ParseError {
     /// The provided value contains a character that is not in the alphabet.
     #[error("Invalid character: {0:x}")]
     InvalidCharacter(u8),
+
+    /// The provided value contains a character that is not in the [`UnicodeAlphabet`](alphabet::UnicodeAlphabet).
+    #[error("Invalid symbol: {0:?}")]
+    InvalidSymbol(char),
+
+    /// The provided value does not start with the expected prefix.
+    #[error("Invalid prefix: expected {expected:?}")]
+    InvalidPrefix {
+        /// The expected prefix.
+        expected: &'static str,
+    },
+
+    /// The check symbols of the provided value don't match the rest of the value.
+    #[error("Invalid checksum")]
+    InvalidChecksum,
+}
+
+/// An error that can occur when a value does not fit in a Nano ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+#[error("Overflow: the value does not fit in the Nano ID")]
+pub struct OverflowError;
+
+/// An error that can occur when creating a [`PseudonymKey`] with an ID that the alphabet can't represent.
+#[cfg(feature = "hmac")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+#[error("Invalid key ID: {id} is not less than the alphabet size {alphabet_size}")]
+pub struct KeyIdError {
+    /// The ID of the key.
+    pub id: u8,
+    /// The size of the alphabet.
+    pub alphabet_size: usize,
+}
+
+/// An error that can occur when creating a [`RuntimeAlphabet`] from invalid symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum AlphabetError {
+    /// The alphabet has no symbols.
+    #[error("Empty alphabet: alphabet must not be empty")]
+    Empty,
+
+    /// The alphabet contains the same symbol more than once.
+    #[error("Duplicate symbol: {0:x}")]
+    DuplicateSymbol(u8),
+
+    /// The alphabet contains a character that is not ASCII.
+    #[error("Non-ASCII symbol: {0:x}")]
+    NonAsciiSymbol(u8),
+}
+
+/// An error that can occur when a new Nano ID collides with existing ones in every attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+#[error("Collision: failed to generate a unique Nano ID in {attempts} attempts")]
+pub struct CollisionError {
+    /// The number of attempts made.
+    pub attempts: usize,
 }
 
 impl<const N: usize, A: Alphabet> Nanoid<N, A> {
+    /// The smallest Nano ID, which consists only of the smallest symbol of the alphabet in byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    /// assert_eq!(Nanoid::<5>::MIN.as_str(), "-----");
+    /// ```
+    pub const MIN: Self = Self::from_inner([A::DIGIT_LIST[0]; N]);
+
+    /// The largest Nano ID, which consists only of the largest symbol of the alphabet in byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    /// assert_eq!(Nanoid::<5>::MAX.as_str(), "zzzzz");
+    /// ```
+    pub const MAX: Self = Self::from_inner([A::DIGIT_LIST[A::VALID_SYMBOL_LIST.len() - 1]; N]);
+
     /// Generate a new Nano ID using random number generator seeded by the system.
     ///
     /// # Panics
@@ -216,7 +350,9 @@ impl<const N: usize, A: Alphabet> Nanoid<N, A> {
     #[allow(clippy::new_without_default)]
     #[must_use]
     pub fn new() -> Self {
-        Self::new_with(rand::rng())
+        let id = Self::new_with(crate::system_rng());
+        crate::track_generated(&id);
+        id
     }
 
     /// Generate a new Nano ID using the provided random number generator.
@@ -235,12 +371,9 @@ impl<const N: usize, A: Alphabet> Nanoid<N, A> {
     /// ```
     #[must_use]
     #[inline]
-    pub fn new_with(mut rng: impl rand::Rng) -> Self {
-        let distr = rand::distr::Uniform::try_from(0..A::VALID_SYMBOL_LIST.len()).unwrap();
-        let inner = std::array::from_fn(|_| A::VALID_SYMBOL_LIST[rng.sample(distr)]);
-
+    pub fn new_with(rng: impl rand::Rng) -> Self {
         Self {
-            inner,
+            inner: random_symbols(A::VALID_SYMBOL_LIST, rng),
             _marker: PhantomData,
         }
     }
@@ -260,8 +393,11 @@ impl<const N: usize, A: Alphabet> Nanoid<N, A> {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub const fn try_from_str(s: &str) -> Result<Self, ParseError> {
-        let s = s.as_bytes();
+        Self::try_from_slice(s.as_bytes())
+    }
 
+    /// Parse a byte slice into a [`Nanoid`], checking its length first.
+    pub(crate) const fn try_from_slice(s: &[u8]) -> Result<Self, ParseError> {
         // This conversion is copied from the `TryFrom` implementation. We can't call `try_from` here because it's not const.
         // https://github.com/rust-lang/rust/blob/7cf61ebde7b22796c69757901dd346d0fe70bd97/library/core/src/array/mod.rs#L250-L264
         // Once this crate's MSRV reaches Rust 1.93, this can be replaced with `<[T]>::as_array`.
@@ -282,6 +418,8 @@ impl<const N: usize, A: Alphabet> Nanoid<N, A> {
 
     /// Parse a byte array into a [`Nanoid`].
     ///
+    /// Aliases defined in [`Alphabet::ALIASES`] are replaced with their canonical symbols.
+    ///
     /// # Errors
     ///
     /// If the byte array contains a character that is not in the alphabet, this method returns [`ParseError::InvalidCharacter`].
@@ -295,16 +433,20 @@ impl<const N: usize, A: Alphabet> Nanoid<N, A> {
     /// ```
     #[inline]
     pub const fn try_from_bytes(buf: &[u8; N]) -> Result<Self, ParseError> {
+        let mut inner = *buf;
         let mut i = 0;
         while i < N {
-            if buf[i] >= A::VALID_SYMBOL_MAP.len() as u8 || !A::VALID_SYMBOL_MAP[buf[i] as usize] {
+            if buf[i] >= A::NORMALIZE_MAP.len() as u8
+                || A::NORMALIZE_MAP[buf[i] as usize] == INVALID_SYMBOL
+            {
                 return Err(ParseError::InvalidCharacter(buf[i]));
             }
+            inner[i] = A::NORMALIZE_MAP[buf[i] as usize];
             i += 1;
         }
 
         Ok(Nanoid {
-            inner: *buf,
+            inner,
             _marker: PhantomData,
         })
     }
@@ -325,6 +467,15 @@ impl<const N: usize, A: Alphabet> Nanoid<N, A> {
         // SAFETY: all characters are ASCII.
         unsafe { std::str::from_utf8_unchecked(&self.inner) }
     }
+
+    /// Create a [`Nanoid`] from a byte array without validation. All elements must be symbols of the alphabet.
+    #[inline]
+    pub(crate) const fn from_inner(inner: [u8; N]) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
 }
 
 // `Copy` cannot be derived due to a limitation of the compiler.
@@ -431,6 +582,101 @@ impl<'de, const N: usize, A: Alphabet> serde::Deserialize<'de> for Nanoid<N, A>
     }
 }
 
+/// Get the random number generator used when no random number generator is provided.
+///
+/// This is the thread-local pool if the `pool` feature is enabled, and [`rand::rng`] otherwise.
+fn system_rng() -> impl rand::CryptoRng {
+    #[cfg(feature = "pool")]
+    {
+        pool::PoolRng
+    }
+
+    #[cfg(not(feature = "pool"))]
+    {
+        rand::rng()
+    }
+}
+
+/// Record a Nano ID generated with [`system_rng`] if the `track-duplicates` feature is enabled.
+#[inline]
+fn track_generated<const N: usize, A: Alphabet>(id: &Nanoid<N, A>) {
+    track_generated_str::<A>(id.as_str());
+}
+
+/// Record the string of a Nano ID generated with [`system_rng`] if the `track-duplicates` feature is enabled.
+#[inline]
+#[cfg_attr(
+    not(feature = "track-duplicates"),
+    allow(clippy::extra_unused_type_parameters)
+)]
+fn track_generated_str<A>(id: &str) {
+    #[cfg(feature = "track-duplicates")]
+    track::record::<A>(id);
+
+    #[cfg(not(feature = "track-duplicates"))]
+    let _ = id;
+}
+
+/// Record the characters of a [`UnicodeNanoid`] generated with [`system_rng`] if the `track-duplicates` feature is enabled.
+#[inline]
+#[cfg_attr(
+    not(feature = "track-duplicates"),
+    allow(clippy::extra_unused_type_parameters)
+)]
+fn track_generated_chars<A>(id: &[char]) {
+    #[cfg(feature = "track-duplicates")]
+    track::record::<A>(&id.iter().collect::<String>());
+
+    #[cfg(not(feature = "track-duplicates"))]
+    let _ = id;
+}
+
+/// Record a Nano ID generated with a [`RuntimeAlphabet`] of the symbols if the `track-duplicates` feature is enabled.
+#[inline]
+fn track_generated_runtime(symbols: &[u8], id: &str) {
+    #[cfg(feature = "track-duplicates")]
+    track::record_runtime(symbols, id);
+
+    #[cfg(not(feature = "track-duplicates"))]
+    let _ = (symbols, id);
+}
+
+/// Fill the buffer with random symbols from the list of symbols, which must be non-empty.
+#[inline]
+fn fill_random_symbols<T: Copy>(symbols: &[T], mut rng: impl rand::Rng, buf: &mut [T]) {
+    // If the alphabet size is a power of two, each symbol needs exactly `log2(len)` random bits.
+    // In this case, symbols can be sliced out of random words without rejection.
+    // For a static alphabet, this condition is evaluated at compile time, so the other branch is eliminated.
+    if symbols.len().is_power_of_two() {
+        let bits = symbols.len().trailing_zeros();
+        let mask = (1 << bits) - 1;
+        let mut word = 0u64;
+        let mut remaining = 0;
+        for symbol in buf {
+            if remaining < bits {
+                word = rng.next_u64();
+                remaining = u64::BITS;
+            }
+            *symbol = symbols[(word & mask) as usize];
+            word >>= bits;
+            remaining -= bits;
+        }
+    } else {
+        let distr = rand::distr::Uniform::try_from(0..symbols.len()).unwrap();
+        for symbol in buf {
+            *symbol = symbols[rng.sample(distr)];
+        }
+    }
+}
+
+/// Generate `N` random symbols from the list of symbols, which must be non-empty.
+#[inline]
+fn random_symbols<const N: usize, T: Copy + Default>(symbols: &[T], rng: impl rand::Rng) -> [T; N] {
+    let mut buf = [T::default(); N];
+    fill_random_symbols(symbols, rng, &mut buf);
+    buf
+}
+
 /// Parse [`Nanoid`]s from strings at compile time.
 ///
 /// This macro transforms a constant string into [`Nanoid`] at compile time.
@@ -472,12 +718,10 @@ macro_rules! nanoid {
         const {
             match $crate::Nanoid::<{ $crate::std::primitive::str::as_bytes($id).len() }$(, $alphabet)?>::try_from_str($id) {
                 $crate::std::result::Result::Ok(id) => id,
-                $crate::std::result::Result::Err($crate::ParseError::InvalidLength { .. }) => {
-                    $crate::std::unreachable!()
-                }
                 $crate::std::result::Result::Err($crate::ParseError::InvalidCharacter(_)) => {
                     $crate::std::panic!("the provided string has invalid character")
                 }
+                $crate::std::result::Result::Err(_) => $crate::std::unreachable!(),
             }
         }
     };
@@ -493,7 +737,7 @@ mod tests {
     use pretty_assertions::{assert_eq, assert_ne};
 
     use super::*;
-    use crate::alphabet::{Base16Alphabet, Base58Alphabet, Base62Alphabet};
+    use crate::alphabet::{Base16Alphabet, Base32Alphabet, Base58Alphabet, Base62Alphabet};
 
     #[test]
     fn test_new_unique() {
@@ -745,6 +989,25 @@ mod tests {
         inner::<12, Base58Alphabet>("abcdefghijkl", b'l');
     }
 
+    #[test]
+    fn test_parse_aliases() {
+        fn inner<const N: usize, A: Alphabet>(s: &str, expected: &str) {
+            let id: Nanoid<N, A> = Nanoid::try_from_str(s).unwrap();
+            assert_eq!(id.as_str(), expected);
+
+            let canonical: Nanoid<N, A> = expected.parse().unwrap();
+            assert_eq!(id, canonical);
+
+            let mut set = HashSet::new();
+            set.insert(id);
+            assert!(set.contains(&canonical));
+        }
+
+        inner::<8, Base32Alphabet>("abcxyz27", "ABCXYZ27");
+        inner::<8, Base32Alphabet>("AbCxYz27", "ABCXYZ27");
+        inner::<8, Base32Alphabet>("ABCXYZ27", "ABCXYZ27");
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serialize() {
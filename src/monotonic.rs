@@ -0,0 +1,266 @@
+use crate::{
+    Nanoid, OverflowError,
+    alphabet::{Alphabet, AlphabetExt, Base64UrlAlphabet},
+    timestamp::now,
+};
+
+/// A generator of time-sortable Nano IDs that are strictly increasing, even within the same millisecond.
+///
+/// When the timestamp advances, the generator creates a new time-sortable Nano ID, just like [`Nanoid::new_sortable`].
+/// Within the same millisecond, it increments the random part of the previous ID instead of generating a new one,
+/// so the IDs sort in the order they were generated.
+/// If the system clock goes backwards, the previous timestamp is kept and the random part is incremented as well.
+///
+/// The state of the generator is the last generated ID. It can be exported with [`MonotonicGenerator::state`]
+/// and restored with [`MonotonicGenerator::from_state`] to keep the order across process restarts.
+///
+/// # Generic parameters
+///
+/// - `N`: The length of the Nano ID. The default is `21`.
+/// - `A`: The alphabet used in the Nano ID. The default is [`Base64UrlAlphabet`].
+///
+/// # Examples
+///
+/// ```
+/// use nid::{MonotonicGenerator, Nanoid};
+///
+/// let mut generator: MonotonicGenerator = MonotonicGenerator::new();
+/// let id1 = generator.generate()?;
+/// let id2 = generator.generate()?;
+/// assert!(id1 < id2);
+///
+/// // Save the state and restore it later.
+/// let state = generator.state();
+/// let mut generator = MonotonicGenerator::from_state(state);
+/// let id3 = generator.generate()?;
+/// assert!(id2 < id3);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct MonotonicGenerator<const N: usize = 21, A: Alphabet = Base64UrlAlphabet> {
+    last: Option<Nanoid<N, A>>,
+}
+
+impl<const N: usize, A: Alphabet> MonotonicGenerator<N, A> {
+    /// Create a new generator with no previous ID.
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { last: None }
+    }
+
+    /// Restore a generator from the state exported by [`MonotonicGenerator::state`].
+    ///
+    /// The state is not validated. If its timestamp is ahead of the clock, the generator keeps that timestamp
+    /// and increments the random part until the clock catches up, returning [`OverflowError`] once the random part
+    /// is exhausted. The timestamp of a state that was not created by a generator may even be greater than
+    /// [`Nanoid::MAX_TIMESTAMP`], in which case the clock never catches up.
+    /// Check [`Nanoid::timestamp`] before restoring a state from an untrusted source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{MonotonicGenerator, Nanoid};
+    ///
+    /// let last: Nanoid = Nanoid::new_sortable();
+    /// let mut generator = MonotonicGenerator::from_state(Some(last));
+    /// assert!(generator.generate()? > last);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub const fn from_state(last: Option<Nanoid<N, A>>) -> Self {
+        Self { last }
+    }
+
+    /// Get the state of the generator, which is the last generated ID.
+    #[must_use]
+    pub const fn state(&self) -> Option<Nanoid<N, A>> {
+        self.last
+    }
+
+    /// Generate a new Nano ID using the current system time and random number generator seeded by the system.
+    ///
+    /// # Errors
+    ///
+    /// If the random part of the Nano ID is exhausted within the same millisecond, this method returns [`OverflowError`].
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new_sortable`].
+    pub fn generate(&mut self) -> Result<Nanoid<N, A>, OverflowError> {
+        let id = self.generate_with(now(), crate::system_rng())?;
+        crate::track_generated(&id);
+        Ok(id)
+    }
+
+    /// Generate a new Nano ID using the provided timestamp and random number generator.
+    ///
+    /// # Errors
+    ///
+    /// If the random part of the Nano ID is exhausted within the same millisecond, this method returns [`OverflowError`].
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new_sortable_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{MonotonicGenerator, Nanoid};
+    ///
+    /// let mut generator: MonotonicGenerator = MonotonicGenerator::new();
+    /// let id1 = generator.generate_with(1_700_000_000_000, rand::rng())?;
+    /// let id2 = generator.generate_with(1_700_000_000_000, rand::rng())?;
+    /// assert!(id1 < id2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn generate_with(
+        &mut self,
+        timestamp: u64,
+        rng: impl rand::Rng,
+    ) -> Result<Nanoid<N, A>, OverflowError> {
+        let id = match self.last {
+            Some(last) if timestamp <= last.timestamp() => increment(last)?,
+            _ => Nanoid::new_sortable_with(timestamp, rng),
+        };
+        self.last = Some(id);
+        Ok(id)
+    }
+}
+
+// `Clone` cannot be derived due to a limitation of the compiler.
+impl<const N: usize, A: Alphabet> Clone for MonotonicGenerator<N, A> {
+    fn clone(&self) -> Self {
+        Self { last: self.last }
+    }
+}
+
+impl<const N: usize, A: Alphabet> std::fmt::Debug for MonotonicGenerator<N, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MonotonicGenerator")
+            .field("last", &self.last)
+            .finish()
+    }
+}
+
+/// Increment the random part of a time-sortable Nano ID by one.
+fn increment<const N: usize, A: Alphabet>(
+    mut id: Nanoid<N, A>,
+) -> Result<Nanoid<N, A>, OverflowError> {
+    let radix = A::VALID_SYMBOL_LIST.len();
+    for symbol in id.inner[Nanoid::<N, A>::TIMESTAMP_LEN..].iter_mut().rev() {
+        let digit = A::DIGIT_MAP[*symbol as usize] as usize + 1;
+        if digit < radix {
+            *symbol = A::DIGIT_LIST[digit];
+            return Ok(id);
+        }
+        *symbol = A::DIGIT_LIST[0];
+    }
+    Err(OverflowError)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::alphabet::{Base16Alphabet, Base36Alphabet, Base58Alphabet, Base62Alphabet};
+
+    #[test]
+    fn test_generate_monotonic() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            let mut generator = MonotonicGenerator::<N, A>::new();
+
+            let mut ids = Vec::new();
+            for timestamp in [1_700_000_000_000, 1_700_000_000_001, 1_699_999_999_999] {
+                for _ in 0..1000 {
+                    ids.push(generator.generate_with(timestamp, &mut rng).unwrap());
+                }
+            }
+
+            assert!(ids.windows(2).all(|w| w[0] < w[1]));
+            assert_eq!(ids[999].timestamp(), 1_700_000_000_000);
+            assert_eq!(ids[1000].timestamp(), 1_700_000_000_001);
+            assert_eq!(ids[2999].timestamp(), 1_700_000_000_001);
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<21, Base62Alphabet>();
+        inner::<21, Base58Alphabet>();
+        inner::<16, Base36Alphabet>();
+        inner::<24, Base16Alphabet>();
+    }
+
+    #[test]
+    fn test_generate_now() {
+        let mut generator: MonotonicGenerator = MonotonicGenerator::new();
+        let ids = (0..1000)
+            .map(|_| generator.generate().unwrap())
+            .collect::<Vec<_>>();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_increment() {
+        fn inner<const N: usize, A: Alphabet>(s: &str, expected: &str) {
+            let id: Nanoid<N, A> = s.parse().unwrap();
+            assert_eq!(increment(id).unwrap().as_str(), expected);
+        }
+
+        inner::<10, Base64UrlAlphabet>("AAAAAAAAAA", "AAAAAAAAAB");
+        inner::<10, Base64UrlAlphabet>("AAAAAAAA-z", "AAAAAAAA0-");
+        inner::<10, Base64UrlAlphabet>("AAAAAAAA9z", "AAAAAAAAA-");
+        inner::<14, Base16Alphabet>("000000000000FE", "000000000000FF");
+        inner::<15, Base16Alphabet>("0000000000000FF", "000000000000100");
+    }
+
+    #[test]
+    fn test_overflow() {
+        let last: Nanoid<10> = "AAAAAAAAzz".parse().unwrap();
+        let mut generator = MonotonicGenerator::from_state(Some(last));
+        let result = generator.generate_with(last.timestamp(), rand::rng());
+        assert_eq!(result, Err(OverflowError));
+        assert_eq!(generator.state(), Some(last));
+
+        // Once the timestamp advances, the generator recovers.
+        let id = generator
+            .generate_with(last.timestamp() + 1, rand::rng())
+            .unwrap();
+        assert!(id > last);
+    }
+
+    #[test]
+    fn test_restore_state_ahead_of_clock() {
+        let last: Nanoid<12, Base62Alphabet> = "zzzzzzzzzzzy".parse().unwrap();
+        assert!(last.timestamp() > Nanoid::<12, Base62Alphabet>::MAX_TIMESTAMP);
+
+        let mut generator = MonotonicGenerator::from_state(Some(last));
+        let id = generator
+            .generate_with(1_700_000_000_000, rand::rng())
+            .unwrap();
+        assert_eq!(id.as_str(), "zzzzzzzzzzzz");
+        assert_eq!(id.timestamp(), last.timestamp());
+
+        let result =
+            generator.generate_with(Nanoid::<12, Base62Alphabet>::MAX_TIMESTAMP, rand::rng());
+        assert_eq!(result, Err(OverflowError));
+    }
+
+    #[test]
+    fn test_restore_state() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mut generator: MonotonicGenerator = MonotonicGenerator::new();
+        assert_eq!(generator.state(), None);
+
+        let id1 = generator
+            .generate_with(1_700_000_000_000, &mut rng)
+            .unwrap();
+        assert_eq!(generator.state(), Some(id1));
+
+        let mut restored = MonotonicGenerator::from_state(generator.state());
+        let id2 = restored.generate_with(1_700_000_000_000, &mut rng).unwrap();
+        assert!(id1 < id2);
+        assert_eq!(id2.timestamp(), 1_700_000_000_000);
+    }
+}
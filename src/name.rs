@@ -0,0 +1,157 @@
+use sha2::{Digest, Sha256};
+
+use crate::{
+    Nanoid,
+    alphabet::{Alphabet, AlphabetExt},
+};
+
+impl<const N: usize, A: Alphabet> Nanoid<N, A> {
+    /// Generate a Nano ID deterministically from a namespace and a name, like UUID version 5.
+    ///
+    /// The same namespace and name always produce the same Nano ID, and the algorithm is guaranteed not
+    /// to change between versions of this crate. This is useful to map external keys to Nano IDs idempotently.
+    /// Use a different namespace for each kind of key to avoid mixing them up.
+    ///
+    /// # Algorithm
+    ///
+    /// 1. Let `prefix` be the namespace length as a big-endian 64-bit integer, followed by the namespace and the name.
+    /// 2. Compute a stream of bytes by concatenating `SHA-256(prefix || counter)` for `counter = 0, 1, 2, ...`,
+    ///    where `counter` is a big-endian 32-bit integer.
+    /// 3. For each symbol, take the next byte from the stream and keep its lowest `ceil(log2(alphabet size))` bits.
+    ///    If the value is less than the alphabet size, use the symbol at that index of [`Alphabet::SYMBOL_LIST`].
+    ///    Otherwise, discard the byte and take the next one, so every symbol is chosen without bias.
+    ///
+    /// Note that the Nano ID is only as unpredictable as the name. Don't use it as a secret.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the provided [`Alphabet`] produces non-ascii characters, but this
+    /// never happens unless the alphabet is implemented incorrectly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base62Alphabet, Nanoid};
+    ///
+    /// let id: Nanoid = Nanoid::from_name("partner-a", "customer-42");
+    /// assert_eq!(id.as_str(), "2q4VyS25rYaqNnVuiq6zC");
+    /// assert_eq!(id, Nanoid::from_name("partner-a", "customer-42"));
+    /// assert_ne!(id, Nanoid::from_name("partner-b", "customer-42"));
+    ///
+    /// let id: Nanoid<16, Base62Alphabet> = Nanoid::from_name(b"partner-a", [0x01, 0x02, 0x03]);
+    /// ```
+    #[must_use]
+    pub fn from_name(namespace: impl AsRef<[u8]>, name: impl AsRef<[u8]>) -> Self {
+        let namespace = namespace.as_ref();
+
+        let mut prefix = Sha256::new();
+        prefix.update((namespace.len() as u64).to_be_bytes());
+        prefix.update(namespace);
+        prefix.update(name.as_ref());
+
+        let stream = (0u32..).flat_map(|counter| {
+            prefix
+                .clone()
+                .chain_update(counter.to_be_bytes())
+                .finalize()
+        });
+
+        Self::from_inner(symbols_from_stream::<N, A>(stream))
+    }
+}
+
+/// Map a stream of uniformly distributed bytes onto the symbols of the alphabet without bias.
+///
+/// See [`uniform_from_stream`] for how the stream is consumed.
+pub(crate) fn symbols_from_stream<const N: usize, A: Alphabet>(
+    mut stream: impl Iterator<Item = u8>,
+) -> [u8; N] {
+    let symbols = A::VALID_SYMBOL_LIST;
+    std::array::from_fn(|_| symbols[usize::from(uniform_from_stream(&mut stream, symbols.len()))])
+}
+
+/// Take a value less than `bound` from a stream of uniformly distributed bytes without bias.
+///
+/// Each byte is masked to `ceil(log2(bound))` bits, and the values out of range are discarded.
+pub(crate) fn uniform_from_stream(stream: &mut impl Iterator<Item = u8>, bound: usize) -> u8 {
+    let mask = bound.next_power_of_two() - 1;
+    loop {
+        let byte = stream.next().expect("the counter is exhausted");
+        if usize::from(byte) & mask < bound {
+            break byte & mask as u8;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use super::*;
+    use crate::alphabet::{
+        Base16Alphabet, Base32Alphabet, Base36Alphabet, Base58Alphabet, Base62Alphabet,
+        Base64UrlAlphabet,
+    };
+
+    #[test]
+    fn test_from_name_vectors() {
+        // These vectors must never change. They were computed with an independent implementation in Python.
+        fn inner<const N: usize, A: Alphabet>(namespace: &str, name: &str, expected: &str) {
+            let id: Nanoid<N, A> = Nanoid::from_name(namespace, name);
+            assert_eq!(id.as_str(), expected);
+        }
+
+        inner::<21, Base64UrlAlphabet>("partner-a", "customer-42", "2q4VyS25rYaqNnVuiq6zC");
+        inner::<21, Base64UrlAlphabet>("", "", "Vs7w1Hy0fCog9k2T45jrZ");
+        inner::<21, Base64UrlAlphabet>("users", "alice@example.com", "wDU8j-pMFh-jw_wCTh3Ka");
+        inner::<21, Base62Alphabet>("users", "alice@example.com", "wDU8jpMFhjwwCTh3KaYtJ");
+        inner::<21, Base58Alphabet>("users", "alice@example.com", "zDWmsNFjmzzCVj7LcawKH");
+        inner::<21, Base58Alphabet>("partner-a", "customer-42", "6t8X2U69uactPqXxkt3CU");
+        inner::<16, Base36Alphabet>("orders", "12345", "6CAYKOUJP0V8M45H");
+        inner::<16, Base32Alphabet>("orders", "12345", "ACAVYKEGOLUYJP2Y");
+        inner::<32, Base16Alphabet>("orders", "12345", "ACAF24E085E23942FC6B8911DA0274D9");
+        inner::<100, Base36Alphabet>(
+            "ns",
+            "name",
+            "8UDJCQEDM72HM7IDMWIMDTEYBTCILFOVTYZKE1OUQXU3KMAF8C8MQG6SY6MY1C2NOSA7556SECGCEE4126EKKYDEEOKNOW0VKB7D",
+        );
+    }
+
+    #[test]
+    fn test_from_name_namespace_boundary() {
+        // The namespace length is hashed, so moving bytes between the namespace and the name changes the ID.
+        let id1: Nanoid = Nanoid::from_name("ab", "c");
+        let id2: Nanoid = Nanoid::from_name("a", "bc");
+        assert_ne!(id1, id2);
+    }
+
+    #[test]
+    fn test_from_name_uniformity() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let mut counts = [0usize; 128];
+            for i in 0..10_000u32 {
+                let id: Nanoid<N, A> = Nanoid::from_name("test", i.to_be_bytes());
+                for &symbol in id.as_str().as_bytes() {
+                    counts[symbol as usize] += 1;
+                }
+            }
+
+            let expected = (10_000 * N) as f64 / A::VALID_SYMBOL_LIST.len() as f64;
+            for &symbol in A::VALID_SYMBOL_LIST {
+                let count = counts[symbol as usize] as f64;
+                assert!(
+                    (count - expected).abs() < expected * 0.1,
+                    "{}: {count}",
+                    symbol as char
+                );
+            }
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<21, Base62Alphabet>();
+        inner::<21, Base58Alphabet>();
+        inner::<21, Base36Alphabet>();
+        inner::<21, Base32Alphabet>();
+        inner::<21, Base16Alphabet>();
+    }
+}
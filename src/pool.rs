@@ -0,0 +1,191 @@
+//! A thread-local pool of random bytes, enabled by the `pool` feature.
+//!
+//! The pool is refilled from the operating system's CSPRNG in large blocks, so generating an ID usually
+//! doesn't need a call to the random number generator at all.
+//!
+//! The pool is fork-safe: on Unix, a fork handler bumps a counter in the child process, and the pool
+//! discards its buffered bytes when it sees the counter changed. Therefore, a child process never
+//! reuses the bytes buffered by its parent.
+
+use std::{
+    cell::RefCell,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use rand::TryRng;
+
+/// The number of bytes requested from the operating system at once.
+const POOL_SIZE: usize = 4096;
+
+/// The number of forks that happened in the history of this process.
+static FORK_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+struct Pool {
+    buf: Box<[u8; POOL_SIZE]>,
+    /// The index of the next unused byte in `buf`.
+    pos: usize,
+    /// The value of [`FORK_COUNT`] when `buf` was filled.
+    fork_count: usize,
+}
+
+impl Pool {
+    fn new() -> Self {
+        register_fork_handler();
+
+        Self {
+            buf: Box::new([0; POOL_SIZE]),
+            pos: POOL_SIZE,
+            fork_count: 0,
+        }
+    }
+
+    fn fill_bytes(&mut self, mut dst: &mut [u8]) {
+        let fork_count = FORK_COUNT.load(Ordering::Relaxed);
+        if self.fork_count != fork_count {
+            // Discard the bytes inherited from the parent process.
+            self.pos = POOL_SIZE;
+            self.fork_count = fork_count;
+        }
+
+        while !dst.is_empty() {
+            if self.pos == POOL_SIZE {
+                rand::rngs::SysRng
+                    .try_fill_bytes(&mut self.buf[..])
+                    .expect("failed to get random bytes from the operating system");
+                self.pos = 0;
+            }
+
+            let len = dst.len().min(POOL_SIZE - self.pos);
+            let (head, tail) = dst.split_at_mut(len);
+            head.copy_from_slice(&self.buf[self.pos..self.pos + len]);
+            // Don't leave the used bytes in memory.
+            self.buf[self.pos..self.pos + len].fill(0);
+            self.pos += len;
+            dst = tail;
+        }
+    }
+}
+
+thread_local! {
+    static POOL: RefCell<Pool> = RefCell::new(Pool::new());
+}
+
+#[cfg(unix)]
+fn register_fork_handler() {
+    static REGISTER: std::sync::Once = std::sync::Once::new();
+
+    extern "C" fn on_fork_child() {
+        FORK_COUNT.fetch_add(1, Ordering::Relaxed);
+    }
+
+    REGISTER.call_once(|| {
+        // SAFETY: `on_fork_child` is async-signal-safe since it only touches an atomic integer.
+        let ret = unsafe { libc::pthread_atfork(None, None, Some(on_fork_child)) };
+        assert!(ret == 0, "failed to register fork handler");
+    });
+}
+
+#[cfg(not(unix))]
+fn register_fork_handler() {}
+
+/// A cryptographically secure random number generator that reads from the thread-local pool.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PoolRng;
+
+impl TryRng for PoolRng {
+    type Error = std::convert::Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut buf = [0; 4];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let mut buf = [0; 8];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        POOL.with_borrow_mut(|pool| pool.fill_bytes(dst));
+        Ok(())
+    }
+}
+
+impl rand::TryCryptoRng for PoolRng {}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_ne};
+    use rand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn test_fill_bytes() {
+        let mut rng = PoolRng;
+
+        // Larger than the pool to cover refilling.
+        let mut buf1 = vec![0; POOL_SIZE * 2 + 1];
+        let mut buf2 = vec![0; POOL_SIZE * 2 + 1];
+        rng.fill_bytes(&mut buf1);
+        rng.fill_bytes(&mut buf2);
+        assert_ne!(buf1, buf2);
+        assert_ne!(rng.next_u64(), rng.next_u64());
+    }
+
+    #[test]
+    fn test_discard_after_fork() {
+        let mut rng = PoolRng;
+        rng.next_u64();
+
+        let (pos, buffered) = POOL.with_borrow(|pool| (pool.pos, pool.buf[pool.pos..].to_vec()));
+        assert_eq!(buffered.len(), POOL_SIZE - pos);
+
+        // Simulate a fork.
+        FORK_COUNT.fetch_add(1, Ordering::Relaxed);
+
+        let mut buf = vec![0; buffered.len()];
+        rng.fill_bytes(&mut buf);
+        assert_ne!(buf, buffered);
+        POOL.with_borrow(|pool| assert_eq!(pool.pos, buffered.len()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_fork() {
+        let mut rng = PoolRng;
+        rng.next_u64();
+
+        let mut fds = [0; 2];
+        // SAFETY: `fds` has room for two file descriptors.
+        assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+
+        // SAFETY: the child process only uses the pool and async-signal-safe functions before exiting.
+        let pid = unsafe { libc::fork() };
+        assert!(pid >= 0);
+        if pid == 0 {
+            let bytes = rng.next_u64().to_le_bytes();
+            // SAFETY: `bytes` is a valid buffer, and `_exit` never returns.
+            unsafe {
+                libc::write(fds[1], bytes.as_ptr().cast(), bytes.len());
+                libc::_exit(0);
+            }
+        }
+
+        let parent = rng.next_u64().to_le_bytes();
+        let mut child = [0u8; 8];
+        // SAFETY: `child` is a valid buffer and `pid` is our child process.
+        unsafe {
+            assert_eq!(
+                libc::read(fds[0], child.as_mut_ptr().cast(), child.len()),
+                8
+            );
+            libc::waitpid(pid, std::ptr::null_mut(), 0);
+            libc::close(fds[0]);
+            libc::close(fds[1]);
+        }
+        assert_ne!(parent, child);
+    }
+}
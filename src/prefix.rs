@@ -0,0 +1,579 @@
+use std::marker::PhantomData;
+
+use crate::{
+    Nanoid, ParseError,
+    alphabet::{Alphabet, Base64UrlAlphabet},
+};
+
+/// A prefix that is prepended to a [`PrefixedNanoid`].
+///
+/// # Implementing a prefix
+///
+/// To implement a prefix, you need to create a new type that implements the [`Prefix`] trait.
+///
+/// ```
+/// use nid::{Prefix, PrefixedNanoid};
+///
+/// struct UserPrefix;
+///
+/// impl Prefix for UserPrefix {
+///     const PREFIX: &'static str = "usr_";
+/// }
+///
+/// type UserId = PrefixedNanoid<UserPrefix, 10>;
+/// let id: UserId = "usr_kP_IH1DPMx".parse()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait Prefix {
+    /// The prefix string, including the separator if any.
+    const PREFIX: &'static str;
+}
+
+/// A Nano ID with a prefix fixed at the type level, such as `usr_kP_IH1DPMx`.
+///
+/// # Generic parameters
+///
+/// - `P`: The prefix of the Nano ID.
+/// - `N`: The length of the Nano ID, excluding the prefix. The default is `21`.
+/// - `A`: The alphabet used in the Nano ID. The default is [`Base64UrlAlphabet`].
+///
+/// # Examples
+///
+/// ```
+/// use nid::{Prefix, PrefixedNanoid};
+///
+/// struct OrderPrefix;
+///
+/// impl Prefix for OrderPrefix {
+///     const PREFIX: &'static str = "ord_";
+/// }
+///
+/// type OrderId = PrefixedNanoid<OrderPrefix, 10>;
+///
+/// // Generate a new prefixed Nano ID and print it.
+/// let id: OrderId = PrefixedNanoid::new();
+/// println!("{}", id);
+///
+/// // Parse a string into a prefixed Nano ID and convert it back to a string.
+/// let id: OrderId = "ord_kP_IH1DPMx".parse()?;
+/// assert_eq!(id.to_string(), "ord_kP_IH1DPMx");
+/// assert_eq!(id.id().as_str(), "kP_IH1DPMx");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// If you try to parse a string with a wrong prefix, you will get an error.
+///
+/// ```
+/// use nid::{ParseError, Prefix, PrefixedNanoid};
+///
+/// struct OrderPrefix;
+///
+/// impl Prefix for OrderPrefix {
+///     const PREFIX: &'static str = "ord_";
+/// }
+///
+/// let result: Result<PrefixedNanoid<OrderPrefix, 10>, _> = "usr_kP_IH1DPMx".parse();
+/// assert!(matches!(result, Err(ParseError::InvalidPrefix { .. })));
+/// ```
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
+pub struct PrefixedNanoid<P: Prefix, const N: usize = 21, A: Alphabet = Base64UrlAlphabet> {
+    id: Nanoid<N, A>,
+
+    _marker: PhantomData<fn() -> P>,
+}
+
+impl<P: Prefix, const N: usize, A: Alphabet> PrefixedNanoid<P, N, A> {
+    /// Generate a new prefixed Nano ID using random number generator seeded by the system.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{Prefix, PrefixedNanoid};
+    ///
+    /// struct UserPrefix;
+    ///
+    /// impl Prefix for UserPrefix {
+    ///     const PREFIX: &'static str = "usr_";
+    /// }
+    ///
+    /// let id: PrefixedNanoid<UserPrefix> = PrefixedNanoid::new();
+    /// ```
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from_nanoid(Nanoid::new())
+    }
+
+    /// Generate a new prefixed Nano ID using the provided random number generator.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{Prefix, PrefixedNanoid};
+    ///
+    /// struct UserPrefix;
+    ///
+    /// impl Prefix for UserPrefix {
+    ///     const PREFIX: &'static str = "usr_";
+    /// }
+    ///
+    /// let id: PrefixedNanoid<UserPrefix> = PrefixedNanoid::new_with(rand::rng());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new_with(rng: impl rand::Rng) -> Self {
+        Self::from_nanoid(Nanoid::new_with(rng))
+    }
+
+    /// Wrap a [`Nanoid`] with the prefix.
+    #[must_use]
+    #[inline]
+    pub const fn from_nanoid(id: Nanoid<N, A>) -> Self {
+        Self {
+            id,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Parse a string into a [`PrefixedNanoid`].
+    ///
+    /// # Errors
+    ///
+    /// - If the string does not start with the prefix, this method returns [`ParseError::InvalidPrefix`].
+    /// - If the length of the string is not equal to the expected length, this method returns [`ParseError::InvalidLength`].
+    /// - If the string contains a character that is not in the alphabet, this method returns [`ParseError::InvalidCharacter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{Prefix, PrefixedNanoid};
+    ///
+    /// struct UserPrefix;
+    ///
+    /// impl Prefix for UserPrefix {
+    ///     const PREFIX: &'static str = "usr_";
+    /// }
+    ///
+    /// let id: PrefixedNanoid<UserPrefix, 10> = PrefixedNanoid::try_from_str("usr_kP_IH1DPMx")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub const fn try_from_str(s: &str) -> Result<Self, ParseError> {
+        let s = s.as_bytes();
+        let prefix = P::PREFIX.as_bytes();
+
+        if s.len() < prefix.len() {
+            return Err(ParseError::InvalidPrefix {
+                expected: P::PREFIX,
+            });
+        }
+        let (head, rest) = s.split_at(prefix.len());
+        let mut i = 0;
+        while i < prefix.len() {
+            if head[i] != prefix[i] {
+                return Err(ParseError::InvalidPrefix {
+                    expected: P::PREFIX,
+                });
+            }
+            i += 1;
+        }
+
+        match Nanoid::try_from_slice(rest) {
+            Ok(id) => Ok(Self::from_nanoid(id)),
+            Err(ParseError::InvalidLength { .. }) => Err(ParseError::InvalidLength {
+                expected: prefix.len() + N,
+                actual: s.len(),
+            }),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the prefix of the [`PrefixedNanoid`].
+    #[must_use]
+    #[inline]
+    pub const fn prefix(&self) -> &'static str {
+        P::PREFIX
+    }
+
+    /// Get the [`Nanoid`] part of the [`PrefixedNanoid`], without the prefix.
+    #[must_use]
+    #[inline]
+    pub const fn id(&self) -> Nanoid<N, A> {
+        self.id
+    }
+}
+
+// `Copy` cannot be derived due to a limitation of the compiler.
+// https://github.com/rust-lang/rust/issues/26925
+impl<P: Prefix, const N: usize, A: Alphabet> Copy for PrefixedNanoid<P, N, A> {}
+
+// `Clone` cannot be derived as well.
+impl<P: Prefix, const N: usize, A: Alphabet> Clone for PrefixedNanoid<P, N, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// `PartialEq` cannot be derived as well.
+impl<P: Prefix, const N: usize, A: Alphabet> PartialEq for PrefixedNanoid<P, N, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+// `Eq` cannot be derived as well.
+impl<P: Prefix, const N: usize, A: Alphabet> Eq for PrefixedNanoid<P, N, A> {}
+
+// `Hash` cannot be derived as well.
+impl<P: Prefix, const N: usize, A: Alphabet> std::hash::Hash for PrefixedNanoid<P, N, A> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+// `PartialOrd` cannot be derived as well.
+impl<P: Prefix, const N: usize, A: Alphabet> PartialOrd for PrefixedNanoid<P, N, A> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// `Ord` cannot be derived as well.
+impl<P: Prefix, const N: usize, A: Alphabet> Ord for PrefixedNanoid<P, N, A> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<P: Prefix, const N: usize, A: Alphabet> std::fmt::Debug for PrefixedNanoid<P, N, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PrefixedNanoid")
+            .field(&format_args!("\"{self}\""))
+            .finish()
+    }
+}
+
+impl<P: Prefix, const N: usize, A: Alphabet> std::fmt::Display for PrefixedNanoid<P, N, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(P::PREFIX)?;
+        f.write_str(self.id.as_str())
+    }
+}
+
+impl<P: Prefix, const N: usize, A: Alphabet> From<PrefixedNanoid<P, N, A>> for String {
+    fn from(id: PrefixedNanoid<P, N, A>) -> Self {
+        id.to_string()
+    }
+}
+
+impl<P: Prefix, const N: usize, A: Alphabet> From<PrefixedNanoid<P, N, A>> for Nanoid<N, A> {
+    fn from(id: PrefixedNanoid<P, N, A>) -> Self {
+        id.id
+    }
+}
+
+impl<P: Prefix, const N: usize, A: Alphabet> From<Nanoid<N, A>> for PrefixedNanoid<P, N, A> {
+    fn from(id: Nanoid<N, A>) -> Self {
+        Self::from_nanoid(id)
+    }
+}
+
+impl<P: Prefix, const N: usize, A: Alphabet> TryFrom<String> for PrefixedNanoid<P, N, A> {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from_str(&s)
+    }
+}
+
+impl<P: Prefix, const N: usize, A: Alphabet> std::str::FromStr for PrefixedNanoid<P, N, A> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<P: Prefix, const N: usize, A: Alphabet> serde::Serialize for PrefixedNanoid<P, N, A> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, P: Prefix, const N: usize, A: Alphabet> serde::Deserialize<'de>
+    for PrefixedNanoid<P, N, A>
+{
+    fn deserialize<D>(deserializer: D) -> Result<PrefixedNanoid<P, N, A>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::try_from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Parse [`PrefixedNanoid`]s from strings at compile time.
+///
+/// This macro transforms a constant string into [`PrefixedNanoid`] at compile time.
+/// If the provided string is not a valid prefixed Nano ID, the program will not compile.
+///
+/// # Arguments
+///
+/// - `$id`: The prefixed Nano ID string.
+/// - `$prefix`: The prefix type of the Nano ID.
+/// - `$alphabet`: The alphabet used in the Nano ID. The default is [`Base64UrlAlphabet`].
+///
+/// # Examples
+///
+/// ```
+/// use nid::{alphabet::Base62Alphabet, prefixed_nanoid, Prefix, PrefixedNanoid};
+///
+/// struct UserPrefix;
+///
+/// impl Prefix for UserPrefix {
+///     const PREFIX: &'static str = "usr_";
+/// }
+///
+/// let id1 = prefixed_nanoid!("usr_kP_IH1DPMx", UserPrefix);
+/// const ID1: PrefixedNanoid<UserPrefix, 10> = prefixed_nanoid!("usr_kP_IH1DPMx", UserPrefix);
+///
+/// // With a different alphabet.
+/// let id2 = prefixed_nanoid!("usr_kPzIH1DPMx", UserPrefix, Base62Alphabet);
+/// const ID2: PrefixedNanoid<UserPrefix, 10, Base62Alphabet> =
+///     prefixed_nanoid!("usr_kPzIH1DPMx", UserPrefix, Base62Alphabet);
+/// ```
+///
+/// # Compilation errors
+///
+/// If the provided string is not a valid prefixed Nano ID, the program will not compile.
+///
+/// ```compile_fail
+/// use nid::{prefixed_nanoid, Prefix};
+///
+/// struct UserPrefix;
+///
+/// impl Prefix for UserPrefix {
+///     const PREFIX: &'static str = "usr_";
+/// }
+///
+/// let id = prefixed_nanoid!("ord_kP_IH1DPMx", UserPrefix); // Compilation error: the provided string has invalid prefix
+/// ```
+#[macro_export]
+macro_rules! prefixed_nanoid {
+    ($id:expr, $prefix:ty $(, $alphabet:ty)? $(,)?) => {
+        const {
+            match $crate::PrefixedNanoid::<
+                $prefix,
+                {
+                    $crate::std::primitive::str::as_bytes($id)
+                        .len()
+                        .saturating_sub($crate::std::primitive::str::as_bytes(<$prefix as $crate::Prefix>::PREFIX).len())
+                }
+                $(, $alphabet)?
+            >::try_from_str($id) {
+                $crate::std::result::Result::Ok(id) => id,
+                $crate::std::result::Result::Err($crate::ParseError::InvalidCharacter(_)) => {
+                    $crate::std::panic!("the provided string has invalid character")
+                }
+                $crate::std::result::Result::Err($crate::ParseError::InvalidPrefix { .. }) => {
+                    $crate::std::panic!("the provided string has invalid prefix")
+                }
+                $crate::std::result::Result::Err(_) => $crate::std::unreachable!(),
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use super::*;
+    use crate::alphabet::{Base16Alphabet, Base58Alphabet, Base62Alphabet};
+
+    struct UserPrefix;
+
+    impl Prefix for UserPrefix {
+        const PREFIX: &'static str = "usr_";
+    }
+
+    struct EmptyPrefix;
+
+    impl Prefix for EmptyPrefix {
+        const PREFIX: &'static str = "";
+    }
+
+    #[test]
+    fn test_new_unique() {
+        fn inner<P: Prefix, const N: usize, A: Alphabet>() {
+            let id1: PrefixedNanoid<P, N, A> = PrefixedNanoid::new();
+            let id2: PrefixedNanoid<P, N, A> = PrefixedNanoid::new();
+            assert_ne!(id1, id2);
+            assert!(id1.to_string().starts_with(P::PREFIX));
+        }
+
+        inner::<UserPrefix, 21, Base64UrlAlphabet>();
+        inner::<UserPrefix, 10, Base62Alphabet>();
+        inner::<EmptyPrefix, 12, Base58Alphabet>();
+    }
+
+    #[test]
+    fn test_hash() {
+        let id1: PrefixedNanoid<UserPrefix, 10> = "usr_kP_IH1DPMx".parse().unwrap();
+        let id2: PrefixedNanoid<UserPrefix, 10> = "usr_kP_IH1DPMx".parse().unwrap();
+
+        let mut set = HashSet::new();
+        set.insert(id1);
+        assert!(set.contains(&id2));
+    }
+
+    #[test]
+    fn test_cmp() {
+        let id1: PrefixedNanoid<UserPrefix, 6> = "usr_abc12-".parse().unwrap();
+        let id2: PrefixedNanoid<UserPrefix, 6> = "usr_abc12_".parse().unwrap();
+        assert_eq!(id1.cmp(&id2), std::cmp::Ordering::Less);
+    }
+
+    #[test]
+    fn test_debug_format() {
+        let id: PrefixedNanoid<UserPrefix, 10> = "usr_kP_IH1DPMx".parse().unwrap();
+        assert_eq!(format!("{:?}", id), "PrefixedNanoid(\"usr_kP_IH1DPMx\")");
+    }
+
+    #[test]
+    fn test_convert_to_string() {
+        fn inner<P: Prefix, const N: usize, A: Alphabet>(s: &str) {
+            let id: PrefixedNanoid<P, N, A> = s.parse().unwrap();
+
+            // Test `Display` trait
+            assert_eq!(format!("{}", id), s);
+
+            // Test `From<PrefixedNanoid>` trait
+            assert_eq!(String::from(id), s);
+
+            // Test `id` method
+            assert_eq!(id.prefix(), P::PREFIX);
+            assert_eq!(id.id().as_str(), &s[P::PREFIX.len()..]);
+        }
+
+        inner::<UserPrefix, 21, Base64UrlAlphabet>("usr_ABCDEFGHIJKLMNOPQ123_");
+        inner::<UserPrefix, 10, Base62Alphabet>("usr_abc1234XYZ");
+        inner::<EmptyPrefix, 12, Base58Alphabet>("abc123XYZ123");
+    }
+
+    #[test]
+    fn test_parse_valid() {
+        fn inner<P: Prefix, const N: usize, A: Alphabet>(s: &str) {
+            let id: PrefixedNanoid<P, N, A> = PrefixedNanoid::try_from_str(s).unwrap();
+            assert_eq!(id.to_string(), s);
+
+            let id: PrefixedNanoid<P, N, A> = s.to_string().try_into().unwrap();
+            assert_eq!(id.to_string(), s);
+
+            let id: PrefixedNanoid<P, N, A> = s.parse().unwrap();
+            assert_eq!(id.to_string(), s);
+        }
+
+        inner::<UserPrefix, 21, Base64UrlAlphabet>("usr_ABCDEFGHIJKLMNOPQ123_");
+        inner::<UserPrefix, 10, Base62Alphabet>("usr_abc1234XYZ");
+        inner::<EmptyPrefix, 12, Base58Alphabet>("abc123XYZ123");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        fn inner<P: Prefix, const N: usize, A: Alphabet>(s: &str, expected: ParseError) {
+            let result: Result<PrefixedNanoid<P, N, A>, _> = PrefixedNanoid::try_from_str(s);
+            assert_eq!(result, Err(expected.clone()));
+
+            let result: Result<PrefixedNanoid<P, N, A>, _> = s.to_string().try_into();
+            assert_eq!(result, Err(expected.clone()));
+
+            let result: Result<PrefixedNanoid<P, N, A>, _> = s.parse();
+            assert_eq!(result, Err(expected));
+        }
+
+        let invalid_prefix = ParseError::InvalidPrefix { expected: "usr_" };
+        inner::<UserPrefix, 10, Base64UrlAlphabet>("ord_kP_IH1DPMx", invalid_prefix.clone());
+        inner::<UserPrefix, 10, Base64UrlAlphabet>("usr", invalid_prefix.clone());
+        inner::<UserPrefix, 10, Base64UrlAlphabet>("kP_IH1DPMx", invalid_prefix);
+        inner::<UserPrefix, 10, Base64UrlAlphabet>(
+            "usr_kP_IH1",
+            ParseError::InvalidLength {
+                expected: 14,
+                actual: 10,
+            },
+        );
+        inner::<EmptyPrefix, 6, Base64UrlAlphabet>(
+            "abcdefg",
+            ParseError::InvalidLength {
+                expected: 6,
+                actual: 7,
+            },
+        );
+        inner::<UserPrefix, 10, Base62Alphabet>(
+            "usr_kP_IH1DPMx",
+            ParseError::InvalidCharacter(b'_'),
+        );
+    }
+
+    #[test]
+    fn test_convert_nanoid() {
+        let id: Nanoid<10> = "kP_IH1DPMx".parse().unwrap();
+        let prefixed: PrefixedNanoid<UserPrefix, 10> = id.into();
+        assert_eq!(prefixed.to_string(), "usr_kP_IH1DPMx");
+        assert_eq!(Nanoid::from(prefixed), id);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let id: PrefixedNanoid<UserPrefix, 10> = "usr_kP_IH1DPMx".parse().unwrap();
+        let serialized = serde_json::to_string(&id).unwrap();
+        assert_eq!(serialized, "\"usr_kP_IH1DPMx\"");
+
+        let deserialized: PrefixedNanoid<UserPrefix, 10> =
+            serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, id);
+
+        let result: Result<PrefixedNanoid<UserPrefix, 10>, _> =
+            serde_json::from_str("\"ord_kP_IH1DPMx\"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_prefixed_nanoid_macro() {
+        {
+            let id = prefixed_nanoid!("usr_vj-JewhEyrcoWbaLEXTp-", UserPrefix);
+            const ID: PrefixedNanoid<UserPrefix> =
+                prefixed_nanoid!("usr_vj-JewhEyrcoWbaLEXTp-", UserPrefix);
+            assert_eq!(id.to_string(), "usr_vj-JewhEyrcoWbaLEXTp-");
+            assert_eq!(ID.to_string(), "usr_vj-JewhEyrcoWbaLEXTp-");
+        }
+
+        {
+            let id = prefixed_nanoid!("usr_5B0AD0A10D", UserPrefix, Base16Alphabet);
+            const ID: PrefixedNanoid<UserPrefix, 10, Base16Alphabet> =
+                prefixed_nanoid!("usr_5B0AD0A10D", UserPrefix, Base16Alphabet);
+            assert_eq!(id.to_string(), "usr_5B0AD0A10D");
+            assert_eq!(ID.to_string(), "usr_5B0AD0A10D");
+        }
+
+        prefixed_nanoid!("usr_vj-JewhEyrcoWbaLEXTp-", UserPrefix,);
+        prefixed_nanoid!("usr_5B0AD0A10D", UserPrefix, Base16Alphabet,);
+    }
+}
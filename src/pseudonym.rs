@@ -0,0 +1,374 @@
+use std::{collections::BTreeMap, marker::PhantomData};
+
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha256;
+
+use crate::{
+    KeyIdError, Nanoid,
+    alphabet::{Alphabet, AlphabetExt, Base64UrlAlphabet},
+    name::symbols_from_stream,
+};
+
+/// A secret key to derive pseudonymous Nano IDs from sensitive values, such as email addresses and phone numbers.
+///
+/// Each key has an ID, which is stored in the first symbol of the derived Nano IDs.
+/// This allows you to rotate keys and still find out which key produced a pseudonymous Nano ID.
+/// See [`PseudonymKeyring`] to manage multiple keys.
+///
+/// # Generic parameters
+///
+/// - `A`: The alphabet of the Nano IDs derived from this key. The default is [`Base64UrlAlphabet`].
+///
+/// # Examples
+///
+/// ```
+/// use nid::{Nanoid, PseudonymKey};
+///
+/// let key: PseudonymKey = PseudonymKey::new(1, b"secret key")?;
+/// let id: Nanoid = Nanoid::from_key(&key, "alice@example.com");
+/// assert_eq!(id.key_id(), 1);
+/// assert_eq!(id, Nanoid::from_key(&key, "alice@example.com"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct PseudonymKey<A: Alphabet = Base64UrlAlphabet> {
+    id: u8,
+    mac: Hmac<Sha256>,
+
+    _marker: PhantomData<fn() -> A>,
+}
+
+impl<A: Alphabet> PseudonymKey<A> {
+    /// Create a new key with the provided ID and secret.
+    ///
+    /// The secret should be at least 32 bytes of random data.
+    ///
+    /// # Errors
+    ///
+    /// If the ID is not less than the size of the alphabet, this method returns [`KeyIdError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base16Alphabet, KeyIdError, PseudonymKey};
+    ///
+    /// assert!(PseudonymKey::<Base16Alphabet>::new(15, b"secret key").is_ok());
+    /// assert_eq!(
+    ///     PseudonymKey::<Base16Alphabet>::new(16, b"secret key").unwrap_err(),
+    ///     KeyIdError { id: 16, alphabet_size: 16 }
+    /// );
+    /// ```
+    pub fn new(id: u8, secret: impl AsRef<[u8]>) -> Result<Self, KeyIdError> {
+        let alphabet_size = A::VALID_SYMBOL_LIST.len();
+        if usize::from(id) >= alphabet_size {
+            return Err(KeyIdError { id, alphabet_size });
+        }
+
+        Ok(Self {
+            id,
+            mac: Hmac::new_from_slice(secret.as_ref()).expect("HMAC accepts keys of any length"),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get the ID of the key.
+    #[must_use]
+    pub const fn id(&self) -> u8 {
+        self.id
+    }
+}
+
+// `Clone` cannot be derived due to a limitation of the compiler.
+// https://github.com/rust-lang/rust/issues/26925
+impl<A: Alphabet> Clone for PseudonymKey<A> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            mac: self.mac.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<A: Alphabet> std::fmt::Debug for PseudonymKey<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Don't print the secret.
+        f.debug_struct("PseudonymKey")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<const N: usize, A: Alphabet> Nanoid<N, A> {
+    /// Derive a pseudonymous Nano ID from a secret key and a value, using HMAC-SHA-256.
+    ///
+    /// The same key and value always produce the same Nano ID, but the value can't be recovered from the Nano ID,
+    /// and the Nano ID can't be computed for another value without the secret.
+    ///
+    /// The first symbol of the Nano ID is the symbol at index [`PseudonymKey::id`] of [`Alphabet::SYMBOL_LIST`],
+    /// so [`Nanoid::key_id`] tells which key produced it.
+    /// The remaining symbols are computed like [`Nanoid::from_name`], except that the stream of bytes is
+    /// `HMAC-SHA-256(secret, value || counter)` for `counter = 0, 1, 2, ...`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the provided [`Alphabet`] produces non-ascii characters, but this
+    /// never happens unless the alphabet is implemented incorrectly.
+    ///
+    /// If the length of the Nano ID is zero, calling this method will result in a compilation error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{Nanoid, PseudonymKey};
+    ///
+    /// let key = PseudonymKey::new(0, b"secret key")?;
+    /// let id: Nanoid = Nanoid::from_key(&key, "alice@example.com");
+    /// assert_eq!(id.as_str(), "Aw_4BbTHkVPUoZDyJ-hQ2");
+    ///
+    /// let key = PseudonymKey::new(1, b"secret key")?;
+    /// let id: Nanoid = Nanoid::from_key(&key, "alice@example.com");
+    /// assert_eq!(id.as_str(), "Bw_4BbTHkVPUoZDyJ-hQ2");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn from_key(key: &PseudonymKey<A>, value: impl AsRef<[u8]>) -> Self {
+        const { assert!(N > 0, "Nano ID is too short to hold a key ID") };
+
+        // The key ID is checked in `PseudonymKey::new`.
+        let key_symbol = A::VALID_SYMBOL_LIST[usize::from(key.id)];
+
+        let value = value.as_ref();
+        let stream = (0u32..).flat_map(|counter| {
+            key.mac
+                .clone()
+                .chain_update(value)
+                .chain_update(counter.to_be_bytes())
+                .finalize()
+                .into_bytes()
+        });
+
+        // The leading zero byte fills the first symbol, which is then replaced by the key ID.
+        let mut inner = symbols_from_stream::<N, A>(std::iter::once(0).chain(stream));
+        inner[0] = key_symbol;
+        Self::from_inner(inner)
+    }
+
+    /// Get the ID of the key that produced a pseudonymous Nano ID.
+    ///
+    /// If the Nano ID was not generated by [`Nanoid::from_key`], the returned value is meaningless.
+    ///
+    /// If the length of the Nano ID is zero, calling this method will result in a compilation error.
+    #[must_use]
+    pub fn key_id(&self) -> u8 {
+        const { assert!(N > 0, "Nano ID is too short to hold a key ID") };
+
+        A::VALID_SYMBOL_LIST
+            .iter()
+            .position(|&symbol| symbol == self.inner[0])
+            .expect("the Nano ID consists of the symbols of the alphabet") as u8
+    }
+}
+
+/// A set of [`PseudonymKey`]s, one of which is used to derive new pseudonymous Nano IDs.
+///
+/// When you rotate the key, the previous keys remain in the keyring, so the Nano IDs derived from them
+/// can still be verified. Remove a key with [`PseudonymKeyring::remove`] once it is no longer needed.
+///
+/// # Generic parameters
+///
+/// - `A`: The alphabet of the Nano IDs derived from the keys. The default is [`Base64UrlAlphabet`].
+///
+/// # Examples
+///
+/// ```
+/// use nid::{Nanoid, PseudonymKey, PseudonymKeyring};
+///
+/// let mut keyring: PseudonymKeyring = PseudonymKeyring::new(PseudonymKey::new(0, b"old secret")?);
+/// let old: Nanoid = keyring.pseudonymize("alice@example.com");
+///
+/// keyring.rotate(PseudonymKey::new(1, b"new secret")?);
+/// let new: Nanoid = keyring.pseudonymize("alice@example.com");
+/// assert_ne!(old, new);
+/// assert_eq!((old.key_id(), new.key_id()), (0, 1));
+///
+/// // Both IDs can be verified as long as the old key is in the keyring.
+/// assert!(keyring.verify(&old, "alice@example.com"));
+/// assert!(keyring.verify(&new, "alice@example.com"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct PseudonymKeyring<A: Alphabet = Base64UrlAlphabet> {
+    keys: BTreeMap<u8, PseudonymKey<A>>,
+    current: u8,
+}
+
+impl<A: Alphabet> PseudonymKeyring<A> {
+    /// Create a new keyring with the provided key as the current key.
+    #[must_use]
+    pub fn new(current: PseudonymKey<A>) -> Self {
+        let id = current.id;
+        Self {
+            keys: BTreeMap::from([(id, current)]),
+            current: id,
+        }
+    }
+
+    /// Get the key used to derive new pseudonymous Nano IDs.
+    #[must_use]
+    pub fn current(&self) -> &PseudonymKey<A> {
+        &self.keys[&self.current]
+    }
+
+    /// Get the key with the provided ID.
+    #[must_use]
+    pub fn get(&self, id: u8) -> Option<&PseudonymKey<A>> {
+        self.keys.get(&id)
+    }
+
+    /// Add a key without making it current. If the keyring has a key with the same ID, it is replaced.
+    pub fn insert(&mut self, key: PseudonymKey<A>) {
+        self.keys.insert(key.id, key);
+    }
+
+    /// Add a key and make it current. If the keyring has a key with the same ID, it is replaced.
+    pub fn rotate(&mut self, key: PseudonymKey<A>) {
+        self.current = key.id;
+        self.insert(key);
+    }
+
+    /// Remove the key with the provided ID. The current key can't be removed.
+    pub fn remove(&mut self, id: u8) -> Option<PseudonymKey<A>> {
+        if id == self.current {
+            return None;
+        }
+        self.keys.remove(&id)
+    }
+
+    /// Derive a pseudonymous Nano ID from a value using the current key. See [`Nanoid::from_key`].
+    #[must_use]
+    pub fn pseudonymize<const N: usize>(&self, value: impl AsRef<[u8]>) -> Nanoid<N, A> {
+        Nanoid::from_key(self.current(), value)
+    }
+
+    /// Check whether a pseudonymous Nano ID was derived from the value, using the key recorded in the Nano ID.
+    ///
+    /// This returns `false` if the keyring doesn't have the key.
+    #[must_use]
+    pub fn verify<const N: usize>(&self, id: &Nanoid<N, A>, value: impl AsRef<[u8]>) -> bool {
+        let Some(key) = self.get(id.key_id()) else {
+            return false;
+        };
+        let expected = Nanoid::<N, A>::from_key(key, value);
+
+        // Compare in constant time so that the expected ID doesn't leak through timing.
+        expected
+            .inner
+            .iter()
+            .zip(&id.inner)
+            .fold(0, |acc, (a, b)| acc | (a ^ b))
+            == 0
+    }
+}
+
+// `Clone` cannot be derived due to a limitation of the compiler.
+// https://github.com/rust-lang/rust/issues/26925
+impl<A: Alphabet> Clone for PseudonymKeyring<A> {
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys.clone(),
+            current: self.current,
+        }
+    }
+}
+
+impl<A: Alphabet> std::fmt::Debug for PseudonymKeyring<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PseudonymKeyring")
+            .field("keys", &self.keys.values())
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use super::*;
+    use crate::alphabet::{Base16Alphabet, Base36Alphabet, Base62Alphabet, Base64UrlAlphabet};
+
+    #[test]
+    fn test_from_key_vectors() {
+        // These vectors must never change. They were computed with an independent implementation in Python.
+        fn inner<const N: usize, A: Alphabet>(id: u8, secret: &str, value: &str, expected: &str) {
+            let key = PseudonymKey::new(id, secret).unwrap();
+            let id: Nanoid<N, A> = Nanoid::from_key(&key, value);
+            assert_eq!(id.as_str(), expected);
+        }
+
+        inner::<21, Base64UrlAlphabet>(
+            0,
+            "secret key",
+            "alice@example.com",
+            "Aw_4BbTHkVPUoZDyJ-hQ2",
+        );
+        inner::<21, Base64UrlAlphabet>(5, "", "", "Fq4Vh45d28Va8f1DlAJmL");
+        inner::<21, Base62Alphabet>(3, "secret key", "+1-555-0100", "DABXisx48hsHSOl42SmGz");
+        inner::<16, Base36Alphabet>(35, "k", "12345", "9MA4MUPRA1KRJZ4J");
+        inner::<32, Base16Alphabet>(
+            15,
+            "secret key",
+            "alice@example.com",
+            "9A82B5D1EF9E23DC39BA0E9242744868",
+        );
+    }
+
+    #[test]
+    fn test_key_id_out_of_range() {
+        assert_eq!(
+            PseudonymKey::<Base16Alphabet>::new(16, "secret key").unwrap_err(),
+            KeyIdError {
+                id: 16,
+                alphabet_size: 16
+            }
+        );
+        assert!(PseudonymKey::<Base62Alphabet>::new(16, "secret key").is_ok());
+        assert!(PseudonymKey::<Base62Alphabet>::new(61, "secret key").is_ok());
+        assert!(PseudonymKey::<Base62Alphabet>::new(62, "secret key").is_err());
+    }
+
+    #[test]
+    fn test_from_key_secret() {
+        let id1: Nanoid = Nanoid::from_key(&PseudonymKey::new(0, "secret 1").unwrap(), "value");
+        let id2: Nanoid = Nanoid::from_key(&PseudonymKey::new(0, "secret 2").unwrap(), "value");
+        assert_ne!(id1, id2);
+    }
+
+    #[test]
+    fn test_keyring() {
+        let mut keyring = PseudonymKeyring::new(PseudonymKey::new(0, "old").unwrap());
+        let old: Nanoid = keyring.pseudonymize("value");
+        keyring.rotate(PseudonymKey::new(1, "new").unwrap());
+        let new: Nanoid = keyring.pseudonymize("value");
+
+        assert_eq!(keyring.current().id(), 1);
+        assert!(keyring.verify(&old, "value"));
+        assert!(keyring.verify(&new, "value"));
+        assert!(!keyring.verify(&new, "other"));
+
+        assert!(keyring.remove(1).is_none());
+        assert_eq!(keyring.remove(0).map(|key| key.id()), Some(0));
+        assert!(!keyring.verify(&old, "value"));
+        assert!(keyring.verify(&new, "value"));
+    }
+
+    #[test]
+    fn test_debug_hides_secret() {
+        let key: PseudonymKey = PseudonymKey::new(7, "hunter2").unwrap();
+        assert_eq!(format!("{key:?}"), "PseudonymKey { id: 7, .. }");
+        let keyring = PseudonymKeyring::new(key);
+        assert_eq!(
+            format!("{keyring:?}"),
+            "PseudonymKeyring { keys: [PseudonymKey { id: 7, .. }], current: 7 }"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    Nanoid, OverflowError,
+    alphabet::{Alphabet, AlphabetExt},
+    bytes::convert_radix,
+    collision::{keyspace, length_for_keyspace},
+};
+
+/// Re-encoding between alphabets.
+///
+/// A Nano ID is interpreted as a number like [`Nanoid::to_u128`], and the number is encoded with another alphabet.
+/// The conversion is lossless and preserves the order of Nano IDs by value, as compared by [`ByValue`](crate::ByValue).
+/// Like [`Nanoid::from_u128`], the re-encoded Nano IDs are generally **not** sorted by value with [`Ord`].
+impl<const N: usize, A: Alphabet> Nanoid<N, A> {
+    /// Re-encode the Nano ID with the alphabet `B` and the length `M`.
+    ///
+    /// The target must be at least [`length_for_reencode::<A, B>(N)`](length_for_reencode) symbols long, so that
+    /// every Nano ID fits in it. Otherwise, calling this method will result in a compilation error.
+    /// Use [`Nanoid::try_reencode`] to convert it back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base36LowercaseAlphabet, Nanoid};
+    ///
+    /// let id: Nanoid = "V1StGXR8_Z5jdHi6B-myT".parse()?;
+    /// let hostname: Nanoid<25, Base36LowercaseAlphabet> = id.reencode();
+    /// assert_eq!(hostname.as_str(), "bktfev3ab43f6elhhrhwwzaqt");
+    /// assert_eq!(hostname.try_reencode()?, id);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// The following fails to compile because a 21-symbol Base64Url Nano ID needs 25 symbols in Base36.
+    ///
+    /// ```compile_fail
+    /// use nid::{alphabet::Base36LowercaseAlphabet, Nanoid};
+    ///
+    /// let id: Nanoid = Nanoid::new();
+    /// let hostname: Nanoid<24, Base36LowercaseAlphabet> = id.reencode();
+    /// ```
+    #[must_use]
+    pub fn reencode<const M: usize, B: Alphabet>(&self) -> Nanoid<M, B> {
+        const {
+            assert!(
+                M >= length_for_reencode::<A, B>(N),
+                "Nano ID is too short to hold the value"
+            );
+        };
+
+        self.try_reencode()
+            .expect("the Nano ID is long enough to hold the value")
+    }
+
+    /// Re-encode the Nano ID with the alphabet `B` and the length `M`, checking that the value fits.
+    ///
+    /// This is the reverse of [`Nanoid::reencode`].
+    ///
+    /// # Errors
+    ///
+    /// If the value of the Nano ID does not fit in `M` symbols of the alphabet `B`, this method returns [`OverflowError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base36LowercaseAlphabet, Nanoid, OverflowError};
+    ///
+    /// let hostname: Nanoid<25, Base36LowercaseAlphabet> = "bktfev3ab43f6elhhrhwwzaqt".parse()?;
+    /// let id: Nanoid = hostname.try_reencode()?;
+    /// assert_eq!(id.as_str(), "V1StGXR8_Z5jdHi6B-myT");
+    ///
+    /// // Not every Base36 Nano ID fits in a Base64Url Nano ID of 21 symbols.
+    /// let hostname: Nanoid<25, Base36LowercaseAlphabet> = "9999999999999999999999999".parse()?;
+    /// assert_eq!(hostname.try_reencode::<21, nid::alphabet::Base64UrlAlphabet>(), Err(OverflowError));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_reencode<const M: usize, B: Alphabet>(&self) -> Result<Nanoid<M, B>, OverflowError> {
+        let digits = self
+            .inner
+            .map(|symbol| A::SYMBOL_INDEX_MAP[usize::from(symbol)]);
+        let mut target = [0; M];
+        convert_radix(
+            &digits,
+            A::VALID_SYMBOL_LIST.len() as u32,
+            &mut target,
+            B::VALID_SYMBOL_LIST.len() as u32,
+        )?;
+        Ok(Nanoid::from_inner(
+            target.map(|digit| B::VALID_SYMBOL_LIST[usize::from(digit)]),
+        ))
+    }
+}
+
+/// Calculate the shortest length of a Nano ID with the alphabet `B` that can hold any Nano ID of `len` symbols with the alphabet `A`.
+///
+/// This is a `const fn`, so it can be used to declare the target type of [`Nanoid::reencode`].
+/// The length is the smallest `M` such that `size of B ^ M >= size of A ^ len`, calculated exactly with integers.
+///
+/// # Panics
+///
+/// This function panics if the alphabet `B` has only one symbol and `size of A ^ len` is larger than one,
+/// or `size of A ^ len` is larger than `2 ^ 4096`.
+///
+/// # Examples
+///
+/// ```
+/// use nid::{
+///     alphabet::{Base16Alphabet, Base36LowercaseAlphabet, Base58Alphabet, Base64UrlAlphabet},
+///     length_for_reencode, Nanoid,
+/// };
+///
+/// assert_eq!(length_for_reencode::<Base64UrlAlphabet, Base36LowercaseAlphabet>(21), 25);
+/// assert_eq!(length_for_reencode::<Base64UrlAlphabet, Base16Alphabet>(21), 32);
+/// assert_eq!(length_for_reencode::<Base16Alphabet, Base64UrlAlphabet>(32), 22);
+///
+/// type Hostname = Nanoid<{ length_for_reencode::<Base64UrlAlphabet, Base36LowercaseAlphabet>(21) }, Base36LowercaseAlphabet>;
+/// let hostname: Hostname = Nanoid::<21>::new().reencode();
+/// ```
+#[must_use]
+pub const fn length_for_reencode<A: Alphabet, B: Alphabet>(len: usize) -> usize {
+    length_for_keyspace::<B>(&keyspace::<A>(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::alphabet::{
+        Base16Alphabet, Base32Alphabet, Base36Alphabet, Base36LowercaseAlphabet, Base58Alphabet,
+        Base62Alphabet, Base64UrlAlphabet,
+    };
+
+    #[test]
+    fn test_length_for_reencode() {
+        assert_eq!(
+            length_for_reencode::<Base64UrlAlphabet, Base64UrlAlphabet>(21),
+            21
+        );
+        assert_eq!(
+            length_for_reencode::<Base64UrlAlphabet, Base62Alphabet>(21),
+            22
+        );
+        assert_eq!(
+            length_for_reencode::<Base64UrlAlphabet, Base58Alphabet>(21),
+            22
+        );
+        assert_eq!(
+            length_for_reencode::<Base64UrlAlphabet, Base36Alphabet>(21),
+            25
+        );
+        assert_eq!(
+            length_for_reencode::<Base64UrlAlphabet, Base32Alphabet>(21),
+            26
+        );
+        assert_eq!(
+            length_for_reencode::<Base64UrlAlphabet, Base16Alphabet>(21),
+            32
+        );
+        assert_eq!(length_for_reencode::<Base16Alphabet, Base32Alphabet>(5), 4);
+        assert_eq!(
+            length_for_reencode::<Base62Alphabet, Base64UrlAlphabet>(0),
+            0
+        );
+    }
+
+    #[test]
+    fn test_reencode_matches_integers() {
+        let value = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        let id: Nanoid<22, Base62Alphabet> = Nanoid::from_u128(value).unwrap();
+        let reencoded: Nanoid<27, Base32Alphabet> = id.reencode();
+        assert_eq!(reencoded.to_u128(), Ok(value));
+    }
+
+    #[test]
+    fn test_reencode_roundtrip_and_order() {
+        fn inner<const N: usize, A: Alphabet, const M: usize, B: Alphabet>() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            let mut ids = vec![Nanoid::<N, A>::MIN, Nanoid::<N, A>::MAX];
+            ids.extend((0..100).map(|_| Nanoid::<N, A>::new_with(&mut rng)));
+            ids.sort_by(Nanoid::cmp_by_value);
+
+            let reencoded: Vec<Nanoid<M, B>> = ids.iter().map(Nanoid::reencode).collect();
+            assert!(reencoded.is_sorted_by(|a, b| a.cmp_by_value(b).is_le()));
+            for (id, reencoded) in ids.iter().zip(&reencoded) {
+                assert_eq!(reencoded.try_reencode(), Ok(*id));
+            }
+        }
+
+        inner::<21, Base64UrlAlphabet, 25, Base36LowercaseAlphabet>();
+        inner::<21, Base64UrlAlphabet, 30, Base36LowercaseAlphabet>();
+        inner::<21, Base62Alphabet, 21, Base64UrlAlphabet>();
+        inner::<21, Base58Alphabet, 32, Base16Alphabet>();
+        inner::<8, Base16Alphabet, 7, Base32Alphabet>();
+    }
+
+    #[test]
+    fn test_try_reencode_overflow() {
+        let max: Nanoid<25, Base36LowercaseAlphabet> = "9".repeat(25).parse().unwrap();
+        assert_eq!(
+            max.try_reencode::<21, Base64UrlAlphabet>(),
+            Err(OverflowError)
+        );
+        assert!(max.try_reencode::<22, Base64UrlAlphabet>().is_ok());
+    }
+}
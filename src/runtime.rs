@@ -0,0 +1,588 @@
+use crate::{
+    AlphabetError, Nanoid, ParseError,
+    alphabet::{Alphabet, AlphabetExt, INVALID_SYMBOL},
+};
+
+/// An alphabet defined at runtime, such as an alphabet loaded from configuration.
+///
+/// Unlike [`Alphabet`], the symbols are validated when the alphabet is created, and an invalid alphabet results in
+/// an [`AlphabetError`] instead of a compilation error. The requirements are the same: the alphabet must be non-empty
+/// and contain only unique ASCII characters.
+///
+/// Nano IDs generated or parsed with a runtime alphabet are represented as [`RuntimeNanoid`]s.
+///
+/// # Examples
+///
+/// ```
+/// use nid::{AlphabetError, RuntimeAlphabet, RuntimeNanoid};
+///
+/// let alphabet = RuntimeAlphabet::new("0123456789abcdef")?;
+///
+/// // Generate a new Nano ID.
+/// let id: RuntimeNanoid<12> = alphabet.new_id();
+///
+/// // Parse a string into a Nano ID.
+/// let id: RuntimeNanoid<12> = alphabet.parse("0123abcd4567")?;
+/// assert_eq!(id.as_str(), "0123abcd4567");
+/// assert!(alphabet.parse::<12>("0123ABCD4567").is_err());
+///
+/// // Invalid alphabets are rejected.
+/// assert_eq!(RuntimeAlphabet::new(""), Err(AlphabetError::Empty));
+/// assert_eq!(RuntimeAlphabet::new("abca"), Err(AlphabetError::DuplicateSymbol(b'a')));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RuntimeAlphabet {
+    /// The symbols. All characters are ASCII.
+    symbols: Box<[u8]>,
+
+    /// A map from an accepted character to its symbol, like [`AlphabetExt::NORMALIZE_MAP`].
+    normalize_map: [u8; 128],
+}
+
+impl RuntimeAlphabet {
+    /// Create a new alphabet with the provided symbols.
+    ///
+    /// # Errors
+    ///
+    /// - If there are no symbols, this method returns [`AlphabetError::Empty`].
+    /// - If a symbol is not an ASCII character, this method returns [`AlphabetError::NonAsciiSymbol`].
+    /// - If a symbol appears more than once, this method returns [`AlphabetError::DuplicateSymbol`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{AlphabetError, RuntimeAlphabet};
+    ///
+    /// let alphabet = RuntimeAlphabet::new("ABCDEFGHJKMNPQRSTVWXYZ")?;
+    /// assert_eq!(alphabet.symbols(), "ABCDEFGHJKMNPQRSTVWXYZ");
+    ///
+    /// assert_eq!(RuntimeAlphabet::new("abcあ"), Err(AlphabetError::NonAsciiSymbol(0xe3)));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(symbols: impl AsRef<[u8]>) -> Result<Self, AlphabetError> {
+        let symbols = symbols.as_ref();
+        if symbols.is_empty() {
+            return Err(AlphabetError::Empty);
+        }
+
+        let mut normalize_map = [INVALID_SYMBOL; 128];
+        for &symbol in symbols {
+            if !symbol.is_ascii() {
+                return Err(AlphabetError::NonAsciiSymbol(symbol));
+            }
+            if normalize_map[usize::from(symbol)] != INVALID_SYMBOL {
+                return Err(AlphabetError::DuplicateSymbol(symbol));
+            }
+            normalize_map[usize::from(symbol)] = symbol;
+        }
+
+        Ok(Self {
+            symbols: symbols.into(),
+            normalize_map,
+        })
+    }
+
+    /// Create a runtime alphabet with the same symbols and aliases as the alphabet `A`.
+    ///
+    /// Nano IDs generated with the same random number generator are the same as the ones generated by [`Nanoid::new_with`],
+    /// and strings are parsed in the same way as [`Nanoid::try_from_str`], replacing [`Alphabet::ALIASES`] with their
+    /// symbols. The aliases are not included in [`RuntimeAlphabet::symbols`] or the serialized form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base32Alphabet, RuntimeAlphabet, RuntimeNanoid};
+    ///
+    /// let alphabet = RuntimeAlphabet::of::<Base32Alphabet>();
+    /// assert_eq!(alphabet.symbols().len(), 32);
+    ///
+    /// let id: RuntimeNanoid<8> = alphabet.parse("abcd2345")?;
+    /// assert_eq!(id.as_str(), "ABCD2345");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn of<A: Alphabet>() -> Self {
+        Self {
+            symbols: A::VALID_SYMBOL_LIST.into(),
+            normalize_map: A::NORMALIZE_MAP,
+        }
+    }
+
+    /// Get the symbols of the alphabet.
+    #[must_use]
+    pub fn symbols(&self) -> &str {
+        // SAFETY: all characters are ASCII.
+        unsafe { std::str::from_utf8_unchecked(&self.symbols) }
+    }
+
+    /// Generate a new Nano ID using random number generator seeded by the system.
+    ///
+    /// # Panics
+    ///
+    /// The function will panic if the random number generator is not able to generate random numbers.
+    #[must_use]
+    pub fn new_id<const N: usize>(&self) -> RuntimeNanoid<N> {
+        let id = self.new_id_with(crate::system_rng());
+        crate::track_generated_runtime(&self.symbols, id.as_str());
+        id
+    }
+
+    /// Generate a new Nano ID using the provided random number generator.
+    ///
+    /// # Panics
+    ///
+    /// The function will panic if the provided random number generator is not able to generate random numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{RuntimeAlphabet, RuntimeNanoid};
+    ///
+    /// let alphabet = RuntimeAlphabet::new("0123456789")?;
+    /// let id: RuntimeNanoid<6> = alphabet.new_id_with(rand::rng());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn new_id_with<const N: usize>(&self, rng: impl rand::Rng) -> RuntimeNanoid<N> {
+        RuntimeNanoid {
+            inner: crate::random_symbols(&self.symbols, rng),
+        }
+    }
+
+    /// Parse a string into a Nano ID with this alphabet.
+    ///
+    /// If the alphabet was created by [`RuntimeAlphabet::of`], aliases are replaced with their symbols.
+    ///
+    /// # Errors
+    ///
+    /// - If the length of the string is not equal to the expected length, this method returns [`ParseError::InvalidLength`].
+    /// - If the string contains a character that is not in the alphabet, this method returns [`ParseError::InvalidCharacter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{ParseError, RuntimeAlphabet, RuntimeNanoid};
+    ///
+    /// let alphabet = RuntimeAlphabet::new("0123456789")?;
+    /// let id: RuntimeNanoid<6> = alphabet.parse("012345")?;
+    /// assert_eq!(alphabet.parse::<6>("01234a"), Err(ParseError::InvalidCharacter(b'a')));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse<const N: usize>(&self, s: &str) -> Result<RuntimeNanoid<N>, ParseError> {
+        let mut inner: [u8; N] =
+            s.as_bytes()
+                .try_into()
+                .map_err(|_| ParseError::InvalidLength {
+                    expected: N,
+                    actual: s.len(),
+                })?;
+        for symbol in &mut inner {
+            let normalized = match self.normalize_map.get(usize::from(*symbol)) {
+                Some(&normalized) if normalized != INVALID_SYMBOL => normalized,
+                _ => return Err(ParseError::InvalidCharacter(*symbol)),
+            };
+            *symbol = normalized;
+        }
+        Ok(RuntimeNanoid { inner })
+    }
+
+    /// Get a [`DeserializeSeed`](serde::de::DeserializeSeed) that deserializes a string into a Nano ID with this alphabet,
+    /// like [`RuntimeAlphabet::parse`].
+    ///
+    /// Since the alphabet is not part of the type, [`RuntimeNanoid`] can't implement [`serde::Deserialize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{RuntimeAlphabet, RuntimeNanoid};
+    /// use serde::de::DeserializeSeed;
+    ///
+    /// let alphabet = RuntimeAlphabet::new("0123456789")?;
+    /// let mut deserializer = serde_json::Deserializer::from_str("\"012345\"");
+    /// let id: RuntimeNanoid<6> = alphabet.seed().deserialize(&mut deserializer)?;
+    /// assert_eq!(id.as_str(), "012345");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub const fn seed<const N: usize>(&self) -> RuntimeNanoidSeed<'_, N> {
+        RuntimeNanoidSeed { alphabet: self }
+    }
+}
+
+impl std::fmt::Debug for RuntimeAlphabet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RuntimeAlphabet")
+            .field(&self.symbols())
+            .finish()
+    }
+}
+
+impl std::str::FromStr for RuntimeAlphabet {
+    type Err = AlphabetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RuntimeAlphabet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.symbols().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RuntimeAlphabet {
+    fn deserialize<D>(deserializer: D) -> Result<RuntimeAlphabet, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::new(s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A Nano ID generated or parsed with a [`RuntimeAlphabet`].
+///
+/// The alphabet is not part of the type, so a [`RuntimeNanoid`] can only be created through a [`RuntimeAlphabet`]
+/// or converted from a [`Nanoid`]. Otherwise, it behaves like a [`Nanoid`].
+///
+/// Use [`RuntimeAlphabet::parse`] or [`RuntimeAlphabet::seed`] to parse or deserialize a [`RuntimeNanoid`], and
+/// [`TryFrom`] to convert it back into a [`Nanoid`] with a static alphabet.
+///
+/// # Equality across alphabets
+///
+/// Since the alphabet is not stored either, [`RuntimeNanoid`]s are compared, hashed and sorted by their strings only.
+/// **Nano IDs of the same string are equal even if they come from different alphabets**, so don't mix Nano IDs of
+/// different alphabets in the same collection unless that is intended.
+///
+/// ```
+/// use nid::{RuntimeAlphabet, RuntimeNanoid};
+///
+/// let id1: RuntimeNanoid<4> = RuntimeAlphabet::new("abc")?.parse("abca")?;
+/// let id2: RuntimeNanoid<4> = RuntimeAlphabet::new("abcdef")?.parse("abca")?;
+/// assert_eq!(id1, id2);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Generic parameters
+///
+/// - `N`: The length of the Nano ID. The default is `21`.
+///
+/// # Examples
+///
+/// ```
+/// use nid::{RuntimeAlphabet, RuntimeNanoid};
+///
+/// let alphabet = RuntimeAlphabet::new("(){}[]<>")?;
+/// let id: RuntimeNanoid<8> = alphabet.parse("{{)((})>")?;
+/// assert_eq!(id.to_string(), "{{)((})>");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
+pub struct RuntimeNanoid<const N: usize = 21> {
+    /// The Nano ID string. All characters are ASCII.
+    inner: [u8; N],
+}
+
+impl<const N: usize> RuntimeNanoid<N> {
+    /// Get the string representation of the [`RuntimeNanoid`].
+    #[must_use]
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: all characters are ASCII.
+        unsafe { std::str::from_utf8_unchecked(&self.inner) }
+    }
+}
+
+impl<const N: usize> std::fmt::Debug for RuntimeNanoid<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RuntimeNanoid")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+impl<const N: usize> std::fmt::Display for RuntimeNanoid<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> From<RuntimeNanoid<N>> for String {
+    fn from(id: RuntimeNanoid<N>) -> Self {
+        id.as_str().to_owned()
+    }
+}
+
+impl<const N: usize> AsRef<str> for RuntimeNanoid<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, A: Alphabet> From<Nanoid<N, A>> for RuntimeNanoid<N> {
+    fn from(id: Nanoid<N, A>) -> Self {
+        Self { inner: id.inner }
+    }
+}
+
+/// Convert a [`RuntimeNanoid`] into a [`Nanoid`] with the alphabet `A`.
+///
+/// Each symbol must be a symbol of `A`. Aliases are not accepted, so the conversion never changes the string.
+///
+/// # Errors
+///
+/// If the Nano ID contains a character that is not a symbol of `A`, the conversion returns [`ParseError::InvalidCharacter`].
+///
+/// # Examples
+///
+/// ```
+/// use nid::{alphabet::Base16Alphabet, Nanoid, ParseError, RuntimeAlphabet, RuntimeNanoid};
+///
+/// let alphabet = RuntimeAlphabet::new("0123456789ABCDEFGHIJ")?;
+/// let id: RuntimeNanoid<4> = alphabet.parse("0A1B")?;
+/// let id: Nanoid<4, Base16Alphabet> = id.try_into()?;
+/// assert_eq!(id.as_str(), "0A1B");
+///
+/// let id: RuntimeNanoid<4> = alphabet.parse("0G1H")?;
+/// assert_eq!(Nanoid::<4, Base16Alphabet>::try_from(id), Err(ParseError::InvalidCharacter(b'G')));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl<const N: usize, A: Alphabet> TryFrom<RuntimeNanoid<N>> for Nanoid<N, A> {
+    type Error = ParseError;
+
+    fn try_from(id: RuntimeNanoid<N>) -> Result<Self, Self::Error> {
+        // All characters of a runtime Nano ID are ASCII, so they can be looked up in the map.
+        if let Some(&symbol) = id
+            .inner
+            .iter()
+            .find(|&&symbol| !A::VALID_SYMBOL_MAP[usize::from(symbol)])
+        {
+            return Err(ParseError::InvalidCharacter(symbol));
+        }
+        Ok(Nanoid::from_inner(id.inner))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for RuntimeNanoid<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+/// A [`DeserializeSeed`](serde::de::DeserializeSeed) that deserializes a string into a [`RuntimeNanoid`] with a
+/// [`RuntimeAlphabet`].
+///
+/// This is created by [`RuntimeAlphabet::seed`].
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy)]
+pub struct RuntimeNanoidSeed<'a, const N: usize = 21> {
+    alphabet: &'a RuntimeAlphabet,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::de::DeserializeSeed<'de> for RuntimeNanoidSeed<'_, N> {
+    type Value = RuntimeNanoid<N>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+
+        let s = String::deserialize(deserializer)?;
+        self.alphabet.parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::alphabet::{
+        Base16Alphabet, Base32Alphabet, Base32CrockfordAlphabet, Base58Alphabet, Base62Alphabet,
+        Base64UrlAlphabet,
+    };
+
+    #[test]
+    fn test_runtime_alphabet_new_invalid() {
+        assert_eq!(RuntimeAlphabet::new(""), Err(AlphabetError::Empty));
+        assert_eq!(
+            RuntimeAlphabet::new("abcb"),
+            Err(AlphabetError::DuplicateSymbol(b'b'))
+        );
+        assert_eq!(
+            RuntimeAlphabet::new(b"abc012\xa0\xa1"),
+            Err(AlphabetError::NonAsciiSymbol(0xa0))
+        );
+        assert_eq!("".parse::<RuntimeAlphabet>(), Err(AlphabetError::Empty));
+    }
+
+    #[test]
+    fn test_runtime_alphabet_matches_static_alphabet() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let alphabet = RuntimeAlphabet::of::<A>();
+            if A::ALIASES.is_empty() {
+                assert_eq!(RuntimeAlphabet::new(A::SYMBOL_LIST), Ok(alphabet.clone()));
+            }
+
+            let mut rng1 = rand::rngs::StdRng::seed_from_u64(0);
+            let mut rng2 = rand::rngs::StdRng::seed_from_u64(0);
+            for _ in 0..100 {
+                let id: Nanoid<N, A> = Nanoid::new_with(&mut rng1);
+                let runtime_id: RuntimeNanoid<N> = alphabet.new_id_with(&mut rng2);
+                assert_eq!(runtime_id, RuntimeNanoid::from(id));
+                assert_eq!(alphabet.parse(id.as_str()), Ok(runtime_id));
+
+                // Aliases are accepted and rejected in the same way.
+                let lowercase = id.as_str().to_ascii_lowercase();
+                assert_eq!(
+                    alphabet.parse(&lowercase),
+                    Nanoid::<N, A>::try_from_str(&lowercase).map(RuntimeNanoid::from)
+                );
+            }
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<21, Base62Alphabet>();
+        inner::<12, Base58Alphabet>();
+        inner::<16, Base32Alphabet>();
+        inner::<16, Base32CrockfordAlphabet>();
+        inner::<7, Base16Alphabet>();
+    }
+
+    #[test]
+    fn test_runtime_alphabet_normalizes_aliases() {
+        let alphabet = RuntimeAlphabet::of::<Base32Alphabet>();
+        let id: RuntimeNanoid<8> = alphabet.parse("abcdWXYZ").unwrap();
+        assert_eq!(id.as_str(), "ABCDWXYZ");
+        assert_eq!(
+            alphabet.parse::<8>("abcd0123"),
+            Err(ParseError::InvalidCharacter(b'0'))
+        );
+
+        // Aliases are not part of the symbols, so they are lost in an alphabet created from the symbols.
+        let alphabet = RuntimeAlphabet::new(alphabet.symbols()).unwrap();
+        assert_eq!(
+            alphabet.parse::<8>("abcdWXYZ"),
+            Err(ParseError::InvalidCharacter(b'a'))
+        );
+    }
+
+    #[test]
+    fn test_runtime_alphabet_parse_invalid() {
+        let alphabet = RuntimeAlphabet::new("abc").unwrap();
+        assert_eq!(
+            alphabet.parse::<4>("abc"),
+            Err(ParseError::InvalidLength {
+                expected: 4,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            alphabet.parse::<4>("abcd"),
+            Err(ParseError::InvalidCharacter(b'd'))
+        );
+        assert_eq!(
+            alphabet.parse::<3>("aア"),
+            Err(ParseError::InvalidLength {
+                expected: 3,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            alphabet.parse::<4>("aア"),
+            Err(ParseError::InvalidCharacter(0xe3))
+        );
+    }
+
+    #[test]
+    fn test_runtime_nanoid_into_nanoid() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            for _ in 0..100 {
+                let id: Nanoid<N, A> = Nanoid::new_with(&mut rng);
+                assert_eq!(Nanoid::try_from(RuntimeNanoid::from(id)), Ok(id));
+            }
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<21, Base62Alphabet>();
+        inner::<16, Base32Alphabet>();
+        inner::<7, Base16Alphabet>();
+
+        let id: RuntimeNanoid<4> = RuntimeAlphabet::new("abc").unwrap().parse("abca").unwrap();
+        assert_eq!(
+            Nanoid::<4, Base16Alphabet>::try_from(id),
+            Err(ParseError::InvalidCharacter(b'a'))
+        );
+        // Aliases are not symbols.
+        assert_eq!(
+            Nanoid::<4, Base32Alphabet>::try_from(id),
+            Err(ParseError::InvalidCharacter(b'a'))
+        );
+    }
+
+    #[test]
+    fn test_runtime_nanoid_ignores_alphabet_in_comparison() {
+        let id1: RuntimeNanoid<4> = RuntimeAlphabet::new("abc").unwrap().parse("abca").unwrap();
+        let id2: RuntimeNanoid<4> = RuntimeAlphabet::new("cba").unwrap().parse("abca").unwrap();
+        assert_eq!(id1, id2);
+    }
+
+    #[test]
+    fn test_runtime_nanoid_format() {
+        let alphabet = RuntimeAlphabet::new("xyz").unwrap();
+        let id: RuntimeNanoid<5> = alphabet.parse("zyxxz").unwrap();
+        assert_eq!(id.as_str(), "zyxxz");
+        assert_eq!(id.to_string(), "zyxxz");
+        assert_eq!(String::from(id), "zyxxz");
+        assert_eq!(format!("{id:?}"), "RuntimeNanoid(\"zyxxz\")");
+        assert_eq!(format!("{alphabet:?}"), "RuntimeAlphabet(\"xyz\")");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_runtime_alphabet_serde() {
+        let alphabet: RuntimeAlphabet = serde_json::from_str("\"0123456789\"").unwrap();
+        assert_eq!(alphabet.symbols(), "0123456789");
+        assert_eq!(serde_json::to_string(&alphabet).unwrap(), "\"0123456789\"");
+        assert!(serde_json::from_str::<RuntimeAlphabet>("\"00\"").is_err());
+
+        let id: RuntimeNanoid<3> = alphabet.parse("042").unwrap();
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"042\"");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_runtime_nanoid_deserialize_seed() {
+        use serde::de::DeserializeSeed;
+
+        let alphabet = RuntimeAlphabet::new("0123456789").unwrap();
+        let id: RuntimeNanoid<3> = alphabet.parse("042").unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        assert_eq!(alphabet.seed().deserialize(&mut deserializer).unwrap(), id);
+
+        let mut deserializer = serde_json::Deserializer::from_str("\"04a\"");
+        assert!(alphabet.seed::<3>().deserialize(&mut deserializer).is_err());
+
+        let mut deserializer = serde_json::Deserializer::from_str("\"0421\"");
+        assert!(alphabet.seed::<3>().deserialize(&mut deserializer).is_err());
+    }
+}
@@ -0,0 +1,315 @@
+use crate::{
+    Nanoid,
+    alphabet::{Alphabet, AlphabetExt},
+};
+
+/// Time-sortable Nano IDs.
+///
+/// A time-sortable Nano ID stores a Unix timestamp in milliseconds in its first [`Nanoid::TIMESTAMP_LEN`] symbols,
+/// followed by random symbols. The timestamp is encoded so that the byte order of the IDs matches their creation order,
+/// which keeps B-tree indexes on such IDs compact.
+///
+/// Note that the timestamp digits follow the byte order of the symbols, not the order of [`Alphabet::SYMBOL_LIST`].
+/// For example, with [`Base62Alphabet`](crate::alphabet::Base62Alphabet), `0` is the smallest digit and `z` is the largest.
+impl<const N: usize, A: Alphabet> Nanoid<N, A> {
+    /// The largest timestamp that can be stored in a time-sortable Nano ID, which is `2^48 - 1` milliseconds.
+    pub const MAX_TIMESTAMP: u64 = MAX_TIMESTAMP;
+
+    /// The number of leading symbols that hold the timestamp in a time-sortable Nano ID.
+    ///
+    /// For example, this is `8` for [`Base64UrlAlphabet`](crate::alphabet::Base64UrlAlphabet) and `9` for
+    /// [`Base62Alphabet`](crate::alphabet::Base62Alphabet).
+    ///
+    /// If the Nano ID is too short to hold a timestamp, reading this constant will result in a compilation error.
+    pub const TIMESTAMP_LEN: usize = {
+        let radix = A::VALID_SYMBOL_LIST.len() as u64;
+        assert!(
+            radix >= 2,
+            "alphabet must have at least two symbols to hold a timestamp"
+        );
+
+        let mut len = 0;
+        let mut capacity = 1;
+        while capacity <= Self::MAX_TIMESTAMP {
+            capacity *= radix;
+            len += 1;
+        }
+
+        assert!(len <= N, "Nano ID is too short to hold a timestamp");
+        len
+    };
+
+    /// Generate a new time-sortable Nano ID using the current system time and random number generator seeded by the system.
+    ///
+    /// # Panics
+    ///
+    /// The function will panic if the system time is before the Unix epoch or after [`Nanoid::MAX_TIMESTAMP`].
+    /// See also [`Nanoid::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    /// let id1: Nanoid = Nanoid::new_sortable();
+    /// std::thread::sleep(std::time::Duration::from_millis(2));
+    /// let id2: Nanoid = Nanoid::new_sortable();
+    /// assert!(id1 < id2);
+    /// ```
+    #[must_use]
+    pub fn new_sortable() -> Self {
+        let id = Self::new_sortable_with(now(), crate::system_rng());
+        crate::track_generated(&id);
+        id
+    }
+
+    /// Generate a new time-sortable Nano ID using the provided timestamp and random number generator.
+    ///
+    /// The timestamp is the number of milliseconds since the Unix epoch.
+    ///
+    /// # Panics
+    ///
+    /// The function will panic if the timestamp is greater than [`Nanoid::MAX_TIMESTAMP`].
+    /// See also [`Nanoid::new_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    /// let id: Nanoid = Nanoid::new_sortable_with(1_700_000_000_000, rand::rng());
+    /// assert_eq!(id.timestamp(), 1_700_000_000_000);
+    /// ```
+    #[must_use]
+    pub fn new_sortable_with(timestamp: u64, rng: impl rand::Rng) -> Self {
+        let mut id = Self::new_with(rng);
+        encode_timestamp::<A>(timestamp, id.inner.split_at_mut(Self::TIMESTAMP_LEN).0);
+        id
+    }
+
+    /// Get the timestamp stored in a time-sortable Nano ID, in milliseconds since the Unix epoch.
+    ///
+    /// If the Nano ID was not generated as a time-sortable Nano ID, the returned value is meaningless
+    /// and may be greater than [`Nanoid::MAX_TIMESTAMP`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    /// let id: Nanoid = Nanoid::new_sortable_with(1_700_000_000_000, rand::rng());
+    /// assert_eq!(id.timestamp(), 1_700_000_000_000);
+    /// ```
+    #[must_use]
+    pub const fn timestamp(&self) -> u64 {
+        let radix = A::VALID_SYMBOL_LIST.len() as u64;
+
+        let mut timestamp = 0;
+        let mut i = 0;
+        while i < Self::TIMESTAMP_LEN {
+            timestamp = timestamp * radix + A::DIGIT_MAP[self.inner[i] as usize] as u64;
+            i += 1;
+        }
+        timestamp
+    }
+
+    /// Get the smallest time-sortable Nano ID with the provided timestamp.
+    ///
+    /// Together with [`Nanoid::max_for_timestamp`], this can be used to find the IDs created in a time range.
+    ///
+    /// # Panics
+    ///
+    /// The function will panic if the timestamp is greater than [`Nanoid::MAX_TIMESTAMP`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    ///
+    /// let id: Nanoid = Nanoid::new_sortable_with(1_700_000_000_500, rand::rng());
+    ///
+    /// // Check if the ID was created between the two timestamps.
+    /// let min = Nanoid::min_for_timestamp(1_700_000_000_000);
+    /// let max = Nanoid::max_for_timestamp(1_700_000_001_000);
+    /// assert!(min <= id && id <= max);
+    /// ```
+    #[must_use]
+    pub const fn min_for_timestamp(timestamp: u64) -> Self {
+        Self::fill_for_timestamp(timestamp, A::DIGIT_LIST[0])
+    }
+
+    /// Get the largest time-sortable Nano ID with the provided timestamp.
+    ///
+    /// Together with [`Nanoid::min_for_timestamp`], this can be used to find the IDs created in a time range.
+    ///
+    /// # Panics
+    ///
+    /// The function will panic if the timestamp is greater than [`Nanoid::MAX_TIMESTAMP`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::Nanoid;
+    ///
+    /// let id: Nanoid = Nanoid::new_sortable_with(1_700_000_000_500, rand::rng());
+    ///
+    /// // Check if the ID was created between the two timestamps.
+    /// let min = Nanoid::min_for_timestamp(1_700_000_000_000);
+    /// let max = Nanoid::max_for_timestamp(1_700_000_001_000);
+    /// assert!(min <= id && id <= max);
+    /// ```
+    #[must_use]
+    pub const fn max_for_timestamp(timestamp: u64) -> Self {
+        Self::fill_for_timestamp(timestamp, A::DIGIT_LIST[A::VALID_SYMBOL_LIST.len() - 1])
+    }
+
+    /// Create a time-sortable Nano ID whose random part is filled with the provided symbol.
+    const fn fill_for_timestamp(timestamp: u64, symbol: u8) -> Self {
+        let mut inner = [symbol; N];
+        encode_timestamp::<A>(timestamp, inner.split_at_mut(Self::TIMESTAMP_LEN).0);
+        Self::from_inner(inner)
+    }
+}
+
+/// The largest timestamp that can be stored in a time-sortable Nano ID.
+const MAX_TIMESTAMP: u64 = (1 << 48) - 1;
+
+/// Get the current system time in milliseconds since the Unix epoch.
+pub(crate) fn now() -> u64 {
+    let elapsed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system time is before the Unix epoch");
+    u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Encode the timestamp into the buffer, filling the whole buffer.
+const fn encode_timestamp<A: Alphabet>(mut timestamp: u64, buf: &mut [u8]) {
+    assert!(
+        timestamp <= MAX_TIMESTAMP,
+        "timestamp is too large to be stored in a Nano ID"
+    );
+
+    let radix = A::VALID_SYMBOL_LIST.len() as u64;
+    let mut i = buf.len();
+    while i > 0 {
+        i -= 1;
+        buf[i] = A::DIGIT_LIST[(timestamp % radix) as usize];
+        timestamp /= radix;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::alphabet::{
+        Base16Alphabet, Base32Alphabet, Base36Alphabet, Base58Alphabet, Base62Alphabet,
+        Base64UrlAlphabet,
+    };
+
+    #[test]
+    fn test_timestamp_len() {
+        assert_eq!(Nanoid::<21, Base64UrlAlphabet>::TIMESTAMP_LEN, 8);
+        assert_eq!(Nanoid::<21, Base62Alphabet>::TIMESTAMP_LEN, 9);
+        assert_eq!(Nanoid::<21, Base58Alphabet>::TIMESTAMP_LEN, 9);
+        assert_eq!(Nanoid::<21, Base36Alphabet>::TIMESTAMP_LEN, 10);
+        assert_eq!(Nanoid::<21, Base32Alphabet>::TIMESTAMP_LEN, 10);
+        assert_eq!(Nanoid::<21, Base16Alphabet>::TIMESTAMP_LEN, 12);
+    }
+
+    #[test]
+    fn test_timestamp_roundtrip() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            for timestamp in [0, 1, 1_700_000_000_000, Nanoid::<N, A>::MAX_TIMESTAMP] {
+                let id: Nanoid<N, A> = Nanoid::new_sortable_with(timestamp, &mut rng);
+                assert_eq!(id.timestamp(), timestamp);
+                assert_eq!(
+                    Nanoid::<N, A>::min_for_timestamp(timestamp).timestamp(),
+                    timestamp
+                );
+                assert_eq!(
+                    Nanoid::<N, A>::max_for_timestamp(timestamp).timestamp(),
+                    timestamp
+                );
+            }
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<21, Base62Alphabet>();
+        inner::<21, Base58Alphabet>();
+        inner::<8, Base64UrlAlphabet>();
+        inner::<10, Base36Alphabet>();
+        inner::<16, Base16Alphabet>();
+    }
+
+    #[test]
+    fn test_timestamp_order() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            let mut ids = (0..1000)
+                .map(|i| {
+                    let timestamp = 1_700_000_000_000 + i * 997;
+                    Nanoid::<N, A>::new_sortable_with(timestamp, &mut rng)
+                })
+                .collect::<Vec<_>>();
+            let timestamps = ids.iter().map(Nanoid::timestamp).collect::<Vec<_>>();
+
+            ids.sort();
+            assert_eq!(
+                ids.iter().map(Nanoid::timestamp).collect::<Vec<_>>(),
+                timestamps
+            );
+
+            // The string representation has the same order.
+            let mut strings = ids.iter().map(Nanoid::to_string).collect::<Vec<_>>();
+            strings.sort();
+            assert_eq!(
+                strings,
+                ids.iter().map(Nanoid::to_string).collect::<Vec<_>>()
+            );
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<21, Base62Alphabet>();
+        inner::<21, Base58Alphabet>();
+        inner::<12, Base36Alphabet>();
+        inner::<16, Base16Alphabet>();
+    }
+
+    #[test]
+    fn test_timestamp_range() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            let timestamp = 1_700_000_000_000;
+            let min = Nanoid::<N, A>::min_for_timestamp(timestamp);
+            let max = Nanoid::<N, A>::max_for_timestamp(timestamp);
+            for _ in 0..1000 {
+                let id = Nanoid::<N, A>::new_sortable_with(timestamp, &mut rng);
+                assert!(min <= id && id <= max);
+            }
+
+            assert!(Nanoid::<N, A>::max_for_timestamp(timestamp - 1) < min);
+            assert!(max < Nanoid::<N, A>::min_for_timestamp(timestamp + 1));
+        }
+
+        inner::<21, Base64UrlAlphabet>();
+        inner::<21, Base62Alphabet>();
+        inner::<21, Base58Alphabet>();
+        inner::<12, Base36Alphabet>();
+        inner::<16, Base16Alphabet>();
+    }
+
+    #[test]
+    fn test_new_sortable() {
+        let before = now();
+        let id: Nanoid = Nanoid::new_sortable();
+        let after = now();
+        assert!(before <= id.timestamp() && id.timestamp() <= after);
+    }
+
+    #[test]
+    #[should_panic = "timestamp is too large to be stored in a Nano ID"]
+    fn test_timestamp_too_large() {
+        let _ = Nanoid::<21>::min_for_timestamp(Nanoid::<21>::MAX_TIMESTAMP + 1);
+    }
+}
@@ -0,0 +1,142 @@
+//! Tracking of generated Nano IDs, enabled by the `track-duplicates` feature.
+//!
+//! Once [`enable_duplicate_tracking`] is called, every Nano ID generated with the system random number
+//! generator is recorded, and generating the same ID twice panics. Tracking only happens in builds with
+//! debug assertions, so it has no cost in release builds.
+
+use std::{
+    collections::HashSet,
+    sync::{
+        Mutex, PoisonError,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static TRACKER: Mutex<Option<Tracker>> = Mutex::new(None);
+
+/// The alphabet of a tracked Nano ID.
+#[derive(PartialEq, Eq, Hash)]
+enum AlphabetKey {
+    /// An alphabet type, identified by its type name.
+    Static(&'static str),
+    /// A [`RuntimeAlphabet`](crate::RuntimeAlphabet), identified by its symbols.
+    Runtime(Box<[u8]>),
+}
+
+/// The set of Nano IDs generated so far, keyed by alphabet since the same string may appear in different types.
+#[derive(Default)]
+struct Tracker {
+    seen: HashSet<(AlphabetKey, Box<[u8]>)>,
+}
+
+impl Tracker {
+    /// Record the Nano ID, and return `false` if it was already recorded.
+    fn insert(&mut self, alphabet: AlphabetKey, id: &str) -> bool {
+        self.seen.insert((alphabet, id.as_bytes().into()))
+    }
+}
+
+/// Start tracking Nano IDs generated in this process, and panic when the same Nano ID is generated twice.
+///
+/// This is intended to be called at the beginning of tests to detect unexpected duplicates.
+/// After this function is called, every Nano ID generated with the random number generator seeded by the system,
+/// such as [`Nanoid::new`](crate::Nanoid::new), [`Nanoid::new_vec`](crate::Nanoid::new_vec),
+/// [`Nanoid::new_sortable`](crate::Nanoid::new_sortable) and [`RuntimeAlphabet::new_id`](crate::RuntimeAlphabet::new_id),
+/// is recorded.
+/// Nano IDs generated with a provided random number generator are not tracked, since a seeded generator
+/// produces the same IDs intentionally.
+///
+/// Tracking only happens when debug assertions are enabled. In release builds, this function does nothing.
+/// Calling this function more than once is allowed.
+///
+/// Note that short Nano IDs can collide by chance, so enable tracking only when the Nano IDs are long enough.
+///
+/// # Examples
+///
+/// ```
+/// use nid::Nanoid;
+///
+/// nid::enable_duplicate_tracking();
+/// let id1: Nanoid = Nanoid::new();
+/// let id2: Nanoid = Nanoid::new(); // This panics if `id2` happens to be equal to `id1`.
+/// ```
+pub fn enable_duplicate_tracking() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Record the generated Nano ID if tracking is enabled.
+///
+/// # Panics
+///
+/// This function panics if the Nano ID was already generated.
+pub(crate) fn record<A>(id: &str) {
+    record_with(|| AlphabetKey::Static(std::any::type_name::<A>()), id);
+}
+
+/// Record the Nano ID generated with a [`RuntimeAlphabet`](crate::RuntimeAlphabet) of the symbols if tracking is enabled.
+///
+/// # Panics
+///
+/// This function panics if the Nano ID was already generated with the same symbols.
+pub(crate) fn record_runtime(symbols: &[u8], id: &str) {
+    record_with(|| AlphabetKey::Runtime(symbols.into()), id);
+}
+
+fn record_with(alphabet: impl FnOnce() -> AlphabetKey, id: &str) {
+    if !cfg!(debug_assertions) || !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let inserted = TRACKER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_or_insert_with(Tracker::default)
+        .insert(alphabet(), id);
+    assert!(inserted, "duplicate Nano ID generated: {id}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Nanoid, RuntimeAlphabet,
+        alphabet::{Base62Alphabet, Base64UrlAlphabet},
+    };
+
+    #[test]
+    fn test_tracker() {
+        let mut tracker = Tracker::default();
+        let id1: Nanoid<4, Base64UrlAlphabet> = "abcd".parse().unwrap();
+        let id2: Nanoid<4, Base64UrlAlphabet> = "abce".parse().unwrap();
+        let id3: Nanoid<4, Base62Alphabet> = "abcd".parse().unwrap();
+
+        let base64url = || AlphabetKey::Static(std::any::type_name::<Base64UrlAlphabet>());
+        let base62 = || AlphabetKey::Static(std::any::type_name::<Base62Alphabet>());
+
+        assert!(tracker.insert(base64url(), id1.as_str()));
+        assert!(tracker.insert(base64url(), id2.as_str()));
+        assert!(tracker.insert(base62(), id3.as_str()));
+        assert!(!tracker.insert(base64url(), id1.as_str()));
+        assert!(!tracker.insert(base62(), id3.as_str()));
+    }
+
+    #[test]
+    fn test_tracker_runtime_alphabet() {
+        let mut tracker = Tracker::default();
+        let alphabet1 = RuntimeAlphabet::new("abcd").unwrap();
+        let alphabet2 = RuntimeAlphabet::new("abcde").unwrap();
+        let key =
+            |alphabet: &RuntimeAlphabet| AlphabetKey::Runtime(alphabet.symbols().as_bytes().into());
+
+        assert!(tracker.insert(key(&alphabet1), "abcd"));
+        assert!(tracker.insert(key(&alphabet2), "abcd"));
+        assert!(tracker.insert(
+            AlphabetKey::Static(std::any::type_name::<Base64UrlAlphabet>()),
+            "abcd"
+        ));
+        assert!(!tracker.insert(key(&alphabet1), "abcd"));
+        assert!(!tracker.insert(key(&RuntimeAlphabet::new("abcde").unwrap()), "abcd"));
+    }
+}
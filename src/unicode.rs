@@ -0,0 +1,394 @@
+use std::marker::PhantomData;
+
+use crate::{
+    ParseError,
+    alphabet::{UnicodeAlphabet, UnicodeAlphabetExt},
+};
+
+/// A Nano ID of arbitrary Unicode characters, such as emoji, CJK or Cyrillic characters.
+///
+/// This is a companion of [`Nanoid`](crate::Nanoid) for a [`UnicodeAlphabet`]. It stores `N` [`char`]s instead of
+/// `N` ASCII bytes, so it can't be borrowed as a `&str`. Prefer [`Nanoid`](crate::Nanoid) for ASCII alphabets.
+///
+/// The Nano IDs are compared by the code points of their characters.
+///
+/// # Generic parameters
+///
+/// - `N`: The length of the Nano ID in characters.
+/// - `A`: The alphabet used in the Nano ID.
+///
+/// # Examples
+///
+/// ```
+/// use nid::{alphabet::UnicodeAlphabet, ParseError, UnicodeNanoid};
+///
+/// struct FruitAlphabet;
+///
+/// impl UnicodeAlphabet for FruitAlphabet {
+///     const SYMBOL_LIST: &'static [char] = &['🍎', '🍊', '🍋', '🍇', '🍒', '🍑', '🍍', '🥝'];
+/// }
+///
+/// type Code = UnicodeNanoid<6, FruitAlphabet>;
+///
+/// // Generate a new Nano ID and print it.
+/// let id: Code = UnicodeNanoid::new();
+/// println!("{}", id);
+///
+/// // Parse a string into a Nano ID and convert it back to a string.
+/// let id: Code = "🍋🍎🥝🍒🍒🍇".parse()?;
+/// assert_eq!(id.to_string(), "🍋🍎🥝🍒🍒🍇");
+///
+/// // The length is counted in characters.
+/// let result: Result<Code, _> = "🍋🍎🥝".parse();
+/// assert_eq!(result, Err(ParseError::InvalidLength { expected: 6, actual: 3 }));
+///
+/// let result: Result<Code, _> = "🍋🍎🥝🍒🍒🍌".parse();
+/// assert_eq!(result, Err(ParseError::InvalidSymbol('🍌')));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
+pub struct UnicodeNanoid<const N: usize, A: UnicodeAlphabet> {
+    inner: [char; N],
+
+    _marker: PhantomData<fn() -> A>,
+}
+
+impl<const N: usize, A: UnicodeAlphabet> UnicodeNanoid<N, A> {
+    /// Generate a new Nano ID using random number generator seeded by the system.
+    ///
+    /// # Panics
+    ///
+    /// The function will panic if the random number generator is not able to generate random numbers.
+    #[allow(clippy::new_without_default)]
+    #[must_use]
+    pub fn new() -> Self {
+        let id = Self::new_with(crate::system_rng());
+        crate::track_generated_chars::<A>(id.as_chars());
+        id
+    }
+
+    /// Generate a new Nano ID using the provided random number generator.
+    ///
+    /// The symbols are chosen in the same way as [`Nanoid::new_with`](crate::Nanoid::new_with).
+    ///
+    /// # Panics
+    ///
+    /// The function will panic if the provided random number generator is not able to generate random numbers.
+    #[must_use]
+    pub fn new_with(rng: impl rand::Rng) -> Self {
+        Self {
+            inner: crate::random_symbols(A::VALID_SYMBOL_LIST, rng),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Parse a string into a [`UnicodeNanoid`].
+    ///
+    /// The length is checked first, like [`Nanoid::try_from_str`](crate::Nanoid::try_from_str).
+    ///
+    /// # Errors
+    ///
+    /// - If the number of characters in the string is not `N`, this method returns [`ParseError::InvalidLength`].
+    /// - If the string contains a character that is not in the alphabet, this method returns [`ParseError::InvalidSymbol`].
+    pub fn try_from_str(s: &str) -> Result<Self, ParseError> {
+        let len = s.chars().count();
+        if len != N {
+            return Err(ParseError::InvalidLength {
+                expected: N,
+                actual: len,
+            });
+        }
+
+        let mut inner = ['\0'; N];
+        for (symbol, c) in inner.iter_mut().zip(s.chars()) {
+            if A::SORTED_SYMBOL_LIST.binary_search(&c).is_err() {
+                return Err(ParseError::InvalidSymbol(c));
+            }
+            *symbol = c;
+        }
+
+        Ok(Self {
+            inner,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get the characters of the [`UnicodeNanoid`].
+    #[must_use]
+    #[inline]
+    pub const fn as_chars(&self) -> &[char; N] {
+        &self.inner
+    }
+}
+
+// `Copy` cannot be derived due to a limitation of the compiler.
+// https://github.com/rust-lang/rust/issues/26925
+impl<const N: usize, A: UnicodeAlphabet> Copy for UnicodeNanoid<N, A> {}
+
+// `Clone` cannot be derived as well.
+impl<const N: usize, A: UnicodeAlphabet> Clone for UnicodeNanoid<N, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// `PartialEq` cannot be derived as well.
+impl<const N: usize, A: UnicodeAlphabet> PartialEq for UnicodeNanoid<N, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+// `Eq` cannot be derived as well.
+impl<const N: usize, A: UnicodeAlphabet> Eq for UnicodeNanoid<N, A> {}
+
+// `Hash` cannot be derived as well.
+impl<const N: usize, A: UnicodeAlphabet> std::hash::Hash for UnicodeNanoid<N, A> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+// `PartialOrd` cannot be derived as well.
+impl<const N: usize, A: UnicodeAlphabet> PartialOrd for UnicodeNanoid<N, A> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// `Ord` cannot be derived as well.
+impl<const N: usize, A: UnicodeAlphabet> Ord for UnicodeNanoid<N, A> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<const N: usize, A: UnicodeAlphabet> std::fmt::Debug for UnicodeNanoid<N, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("UnicodeNanoid")
+            .field(&self.to_string())
+            .finish()
+    }
+}
+
+impl<const N: usize, A: UnicodeAlphabet> std::fmt::Display for UnicodeNanoid<N, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner
+            .iter()
+            .try_for_each(|c| std::fmt::Write::write_char(f, *c))
+    }
+}
+
+impl<const N: usize, A: UnicodeAlphabet> From<UnicodeNanoid<N, A>> for String {
+    fn from(id: UnicodeNanoid<N, A>) -> Self {
+        id.inner.iter().collect()
+    }
+}
+
+impl<const N: usize, A: UnicodeAlphabet> TryFrom<String> for UnicodeNanoid<N, A> {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from_str(&s)
+    }
+}
+
+impl<const N: usize, A: UnicodeAlphabet> std::str::FromStr for UnicodeNanoid<N, A> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize, A: UnicodeAlphabet> serde::Serialize for UnicodeNanoid<N, A> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize, A: UnicodeAlphabet> serde::Deserialize<'de> for UnicodeNanoid<N, A> {
+    fn deserialize<D>(deserializer: D) -> Result<UnicodeNanoid<N, A>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::try_from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        Nanoid,
+        alphabet::{Alphabet, Base62Alphabet},
+    };
+
+    struct CyrillicAlphabet;
+
+    impl UnicodeAlphabet for CyrillicAlphabet {
+        const SYMBOL_LIST: &'static [char] = &[
+            'а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з', 'и', 'к', 'л', 'м', 'н', 'о', 'п', 'р',
+        ];
+    }
+
+    struct CjkAlphabet;
+
+    impl UnicodeAlphabet for CjkAlphabet {
+        const SYMBOL_LIST: &'static [char] =
+            &['山', '川', '田', '木', '林', '森', '火', '水', '金'];
+    }
+
+    /// An ASCII alphabet as a Unicode alphabet, to compare with [`Nanoid`].
+    struct UnicodeBase62Alphabet;
+
+    impl UnicodeAlphabet for UnicodeBase62Alphabet {
+        const SYMBOL_LIST: &'static [char] = &{
+            let mut symbols = ['\0'; 62];
+            let mut i = 0;
+            while i < symbols.len() {
+                symbols[i] = Base62Alphabet::SYMBOL_LIST[i] as char;
+                i += 1;
+            }
+            symbols
+        };
+    }
+
+    /// The largest Unicode alphabet, with the CJK characters from U+4E00 in reverse order.
+    struct LargeCjkAlphabet;
+
+    impl UnicodeAlphabet for LargeCjkAlphabet {
+        const SYMBOL_LIST: &'static [char] = &{
+            let mut symbols = ['\0'; 4096];
+            let mut i = 0;
+            while i < symbols.len() {
+                symbols[i] = char::from_u32(0x4e00 + 4095 - i as u32).unwrap();
+                i += 1;
+            }
+            symbols
+        };
+    }
+
+    #[test]
+    fn test_unicode_sorted_symbols() {
+        fn inner<A: UnicodeAlphabet>() {
+            let mut expected = A::SYMBOL_LIST.to_vec();
+            expected.sort_unstable();
+            assert_eq!(A::SORTED_SYMBOL_LIST, expected);
+        }
+
+        inner::<CyrillicAlphabet>();
+        inner::<CjkAlphabet>();
+        inner::<UnicodeBase62Alphabet>();
+        inner::<LargeCjkAlphabet>();
+    }
+
+    #[test]
+    fn test_unicode_matches_nanoid() {
+        let mut rng1 = rand::rngs::StdRng::seed_from_u64(0);
+        let mut rng2 = rand::rngs::StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let id: Nanoid<21, Base62Alphabet> = Nanoid::new_with(&mut rng1);
+            let unicode_id: UnicodeNanoid<21, UnicodeBase62Alphabet> =
+                UnicodeNanoid::new_with(&mut rng2);
+            assert_eq!(unicode_id.to_string(), id.as_str());
+        }
+    }
+
+    #[test]
+    fn test_unicode_new() {
+        fn inner<const N: usize, A: UnicodeAlphabet>() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            let ids: HashSet<UnicodeNanoid<N, A>> = (0..100)
+                .map(|_| UnicodeNanoid::new_with(&mut rng))
+                .collect();
+            assert_eq!(ids.len(), 100);
+            for id in ids {
+                assert!(id.as_chars().iter().all(|c| A::SYMBOL_LIST.contains(c)));
+                assert_eq!(id.to_string().parse(), Ok(id));
+            }
+        }
+
+        inner::<10, CyrillicAlphabet>();
+        inner::<12, CjkAlphabet>();
+        inner::<21, UnicodeBase62Alphabet>();
+        inner::<8, LargeCjkAlphabet>();
+    }
+
+    #[test]
+    fn test_unicode_parse() {
+        let id: UnicodeNanoid<4, CjkAlphabet> = "山川森金".parse().unwrap();
+        assert_eq!(id.as_chars(), &['山', '川', '森', '金']);
+        assert_eq!(id.to_string(), "山川森金");
+        assert_eq!(String::from(id), "山川森金");
+        assert_eq!(format!("{id:?}"), "UnicodeNanoid(\"山川森金\")");
+        assert_eq!(UnicodeNanoid::try_from("山川森金".to_string()), Ok(id));
+    }
+
+    #[test]
+    fn test_unicode_parse_invalid() {
+        type Id = UnicodeNanoid<4, CjkAlphabet>;
+        assert_eq!(
+            "山川森".parse::<Id>(),
+            Err(ParseError::InvalidLength {
+                expected: 4,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            "山川森金金".parse::<Id>(),
+            Err(ParseError::InvalidLength {
+                expected: 4,
+                actual: 5
+            })
+        );
+        assert_eq!("山川x金".parse::<Id>(), Err(ParseError::InvalidSymbol('x')));
+
+        // The length is checked before the symbols.
+        assert_eq!(
+            "山川森金土".parse::<Id>(),
+            Err(ParseError::InvalidLength {
+                expected: 4,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            "x山".parse::<Id>(),
+            Err(ParseError::InvalidLength {
+                expected: 4,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_unicode_cmp() {
+        let a: UnicodeNanoid<2, CyrillicAlphabet> = "аб".parse().unwrap();
+        let b: UnicodeNanoid<2, CyrillicAlphabet> = "ба".parse().unwrap();
+        assert!(a < b);
+        assert_eq!(a.max(b), b);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_unicode_serde() {
+        let id: UnicodeNanoid<4, CjkAlphabet> = "山川森金".parse().unwrap();
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"山川森金\"");
+        assert_eq!(
+            serde_json::from_str::<UnicodeNanoid<4, CjkAlphabet>>("\"山川森金\"").unwrap(),
+            id
+        );
+        assert!(serde_json::from_str::<UnicodeNanoid<4, CjkAlphabet>>("\"山川森\"").is_err());
+    }
+}
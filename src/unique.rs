@@ -0,0 +1,192 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    hash::BuildHasher,
+};
+
+use crate::{CollisionError, Nanoid, alphabet::Alphabet};
+
+/// A set of existing Nano IDs, which is used to avoid collisions when generating a new Nano ID.
+///
+/// This trait is implemented for [`HashSet`], [`BTreeSet`], slices and closures that take a Nano ID and
+/// return whether it already exists. Implement it for your own type to check against a database or a cache.
+///
+/// # Examples
+///
+/// ```
+/// use nid::{IdSet, Nanoid};
+///
+/// struct Table(Vec<Nanoid<8>>);
+///
+/// impl IdSet<8> for Table {
+///     fn contains(&self, id: &Nanoid<8>) -> bool {
+///         self.0.contains(id)
+///     }
+/// }
+///
+/// let table = Table(vec![Nanoid::new(), Nanoid::new()]);
+/// let id = Nanoid::new_unique(&table, 10)?;
+/// assert!(!table.contains(&id));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait IdSet<const N: usize, A: Alphabet = crate::alphabet::Base64UrlAlphabet> {
+    /// Returns `true` if the set contains the Nano ID.
+    fn contains(&self, id: &Nanoid<N, A>) -> bool;
+}
+
+impl<const N: usize, A: Alphabet, S: BuildHasher> IdSet<N, A> for HashSet<Nanoid<N, A>, S> {
+    fn contains(&self, id: &Nanoid<N, A>) -> bool {
+        HashSet::contains(self, id)
+    }
+}
+
+impl<const N: usize, A: Alphabet> IdSet<N, A> for BTreeSet<Nanoid<N, A>> {
+    fn contains(&self, id: &Nanoid<N, A>) -> bool {
+        BTreeSet::contains(self, id)
+    }
+}
+
+impl<const N: usize, A: Alphabet> IdSet<N, A> for [Nanoid<N, A>] {
+    fn contains(&self, id: &Nanoid<N, A>) -> bool {
+        <[Nanoid<N, A>]>::contains(self, id)
+    }
+}
+
+impl<const N: usize, A: Alphabet, F: Fn(&Nanoid<N, A>) -> bool> IdSet<N, A> for F {
+    fn contains(&self, id: &Nanoid<N, A>) -> bool {
+        self(id)
+    }
+}
+
+impl<const N: usize, A: Alphabet> Nanoid<N, A> {
+    /// Generate a new Nano ID that is not in `existing`, using random number generator seeded by the system.
+    ///
+    /// A new Nano ID is generated up to `max_attempts` times until it is not in `existing`.
+    ///
+    /// # Errors
+    ///
+    /// If all the attempts collide with `existing`, this method returns [`CollisionError`].
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    ///
+    /// use nid::{alphabet::Base58Alphabet, Nanoid};
+    ///
+    /// let mut existing = HashSet::new();
+    /// for _ in 0..100 {
+    ///     let id: Nanoid<8, Base58Alphabet> = Nanoid::new_unique(&existing, 10)?;
+    ///     existing.insert(id);
+    /// }
+    /// assert_eq!(existing.len(), 100);
+    ///
+    /// // A closure can be used as well.
+    /// let id: Nanoid<8, Base58Alphabet> = Nanoid::new_unique(&|id: &Nanoid<8, Base58Alphabet>| existing.contains(id), 10)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new_unique(
+        existing: &(impl IdSet<N, A> + ?Sized),
+        max_attempts: usize,
+    ) -> Result<Self, CollisionError> {
+        // Only the accepted ID is tracked, since rejected ones are expected to be duplicates.
+        let id = Self::new_unique_with(existing, max_attempts, crate::system_rng())?;
+        crate::track_generated(&id);
+        Ok(id)
+    }
+
+    /// Generate a new Nano ID that is not in `existing`, using the provided random number generator.
+    ///
+    /// A new Nano ID is generated up to `max_attempts` times until it is not in `existing`.
+    ///
+    /// # Errors
+    ///
+    /// If all the attempts collide with `existing`, this method returns [`CollisionError`].
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    ///
+    /// use nid::Nanoid;
+    ///
+    /// let existing: BTreeSet<Nanoid<6>> = BTreeSet::new();
+    /// let id: Nanoid<6> = Nanoid::new_unique_with(&existing, 10, rand::rng())?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new_unique_with(
+        existing: &(impl IdSet<N, A> + ?Sized),
+        max_attempts: usize,
+        mut rng: impl rand::Rng,
+    ) -> Result<Self, CollisionError> {
+        (0..max_attempts)
+            .map(|_| Self::new_with(&mut rng))
+            .find(|id| !existing.contains(id))
+            .ok_or(CollisionError {
+                attempts: max_attempts,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::alphabet::{Base16Alphabet, Base58Alphabet, Base64UrlAlphabet};
+
+    #[test]
+    fn test_new_unique() {
+        fn inner<const N: usize, A: Alphabet>() {
+            let mut existing = HashSet::new();
+            for _ in 0..1000 {
+                let id: Nanoid<N, A> = Nanoid::new_unique(&existing, 100).unwrap();
+                assert!(existing.insert(id));
+            }
+        }
+
+        inner::<8, Base58Alphabet>();
+        inner::<21, Base64UrlAlphabet>();
+        // Only 4096 IDs exist, so collisions are frequent.
+        inner::<3, Base16Alphabet>();
+    }
+
+    #[test]
+    fn test_new_unique_skips_existing() {
+        // The same seed generates the same sequence of IDs.
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let first: Nanoid = Nanoid::new_with(&mut rng);
+        let second: Nanoid = Nanoid::new_with(&mut rng);
+
+        let rng = rand::rngs::StdRng::seed_from_u64(0);
+        let id = Nanoid::new_unique_with(&BTreeSet::from([first]), 2, rng).unwrap();
+        assert_eq!(id, second);
+
+        let rng = rand::rngs::StdRng::seed_from_u64(0);
+        let id = Nanoid::new_unique_with(&[first][..], 2, rng).unwrap();
+        assert_eq!(id, second);
+    }
+
+    #[test]
+    fn test_new_unique_gives_up() {
+        let calls = std::cell::Cell::new(0);
+        let existing = |_: &Nanoid| {
+            calls.set(calls.get() + 1);
+            true
+        };
+        let result = Nanoid::new_unique(&existing, 5);
+        assert_eq!(result, Err(CollisionError { attempts: 5 }));
+        assert_eq!(calls.get(), 5);
+
+        let result = Nanoid::new_unique(&|_: &Nanoid| false, 0);
+        assert_eq!(result, Err(CollisionError { attempts: 0 }));
+    }
+}
@@ -0,0 +1,234 @@
+use uuid::{Builder, Uuid};
+
+use crate::{
+    Nanoid, OverflowError,
+    alphabet::{Alphabet, Base58Alphabet, Base62Alphabet, Base64UrlAlphabet},
+    timestamp::now,
+};
+
+/// UUID interoperability.
+///
+/// A UUID is encoded like [`Nanoid::from_byte_array`], so the conversion is lossless and preserves the order of UUIDs
+/// by value, as compared by [`ByValue`](crate::ByValue). In particular, Nano IDs converted from UUIDv7 are sorted by
+/// their timestamps in that order, but generally **not** with [`Ord`].
+impl<const N: usize, A: Alphabet> Nanoid<N, A> {
+    /// Generate a new Nano ID with the bit layout of a UUIDv4, using random number generator seeded by the system.
+    ///
+    /// The Nano ID can be converted into a [`Uuid`] and stored in a native UUID column.
+    /// It has 122 bits of entropy, which is less than a random Nano ID of the same length.
+    ///
+    /// The Nano ID must be long enough to hold 16 bytes. Otherwise, calling this method will result in a compilation error.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base62Alphabet, Nanoid};
+    /// use uuid::Uuid;
+    ///
+    /// let id: Nanoid<22, Base62Alphabet> = Nanoid::new_uuid_v4();
+    /// let uuid = Uuid::try_from(id)?;
+    /// assert_eq!(uuid.get_version_num(), 4);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn new_uuid_v4() -> Self {
+        let id = Self::new_uuid_v4_with(crate::system_rng());
+        crate::track_generated(&id);
+        id
+    }
+
+    /// Generate a new Nano ID with the bit layout of a UUIDv4, using the provided random number generator.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base58Alphabet, Nanoid};
+    ///
+    /// let id: Nanoid<22, Base58Alphabet> = Nanoid::new_uuid_v4_with(rand::rng());
+    /// ```
+    #[must_use]
+    pub fn new_uuid_v4_with(mut rng: impl rand::Rng) -> Self {
+        let mut bytes = [0; 16];
+        rng.fill_bytes(&mut bytes);
+        Self::from_byte_array(Builder::from_random_bytes(bytes).as_uuid().as_bytes())
+    }
+
+    /// Generate a new Nano ID with the bit layout of a UUIDv7, using the current system time and random number generator seeded by the system.
+    ///
+    /// Like [`Nanoid::new_sortable`], the Nano IDs are sorted by the time they were generated, but only when wrapped in
+    /// [`ByValue`](crate::ByValue), not with [`Ord`], which compares the symbols in byte order. Use
+    /// [`Nanoid::new_sortable`] if the Nano IDs or their string representations need to sort by time as they are.
+    /// They can also be converted into [`Uuid`]s and stored in a native UUID column, which is sorted by the time as well.
+    ///
+    /// The Nano ID must be long enough to hold 16 bytes. Otherwise, calling this method will result in a compilation error.
+    ///
+    /// # Panics
+    ///
+    /// The function will panic if the system time is before the Unix epoch. See also [`Nanoid::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base62Alphabet, ByValue, Nanoid};
+    /// use uuid::Uuid;
+    ///
+    /// let id1: Nanoid<22, Base62Alphabet> = Nanoid::new_uuid_v7();
+    /// std::thread::sleep(std::time::Duration::from_millis(2));
+    /// let id2: Nanoid<22, Base62Alphabet> = Nanoid::new_uuid_v7();
+    /// assert!(ByValue(id1) < ByValue(id2));
+    /// assert_eq!(Uuid::try_from(id1)?.get_version_num(), 7);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn new_uuid_v7() -> Self {
+        let id = Self::new_uuid_v7_with(now(), crate::system_rng());
+        crate::track_generated(&id);
+        id
+    }
+
+    /// Generate a new Nano ID with the bit layout of a UUIDv7, using the provided timestamp and random number generator.
+    ///
+    /// The timestamp is the number of milliseconds since the Unix epoch. Only its lowest 48 bits are stored.
+    ///
+    /// # Panics
+    ///
+    /// See [`Nanoid::new_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nid::{alphabet::Base62Alphabet, Nanoid};
+    /// use uuid::Uuid;
+    ///
+    /// let id: Nanoid<22, Base62Alphabet> = Nanoid::new_uuid_v7_with(1_700_000_000_000, rand::rng());
+    /// let uuid = Uuid::try_from(id)?;
+    /// assert_eq!(uuid.get_timestamp().unwrap().to_unix(), (1_700_000_000, 0));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn new_uuid_v7_with(timestamp: u64, mut rng: impl rand::Rng) -> Self {
+        let mut bytes = [0; 10];
+        rng.fill_bytes(&mut bytes);
+        Self::from_byte_array(
+            Builder::from_unix_timestamp_millis(timestamp, &bytes)
+                .as_uuid()
+                .as_bytes(),
+        )
+    }
+}
+
+/// Implement the conversions between [`Uuid`] and Nano IDs of the shortest length that can hold a UUID.
+macro_rules! impl_uuid_conversion {
+    ($len:literal, $alphabet:ty) => {
+        impl From<Uuid> for Nanoid<$len, $alphabet> {
+            fn from(uuid: Uuid) -> Self {
+                Self::from_byte_array(uuid.as_bytes())
+            }
+        }
+
+        impl TryFrom<Nanoid<$len, $alphabet>> for Uuid {
+            type Error = OverflowError;
+
+            fn try_from(id: Nanoid<$len, $alphabet>) -> Result<Self, Self::Error> {
+                id.to_byte_array().map(Uuid::from_bytes)
+            }
+        }
+    };
+}
+
+impl_uuid_conversion!(22, Base64UrlAlphabet);
+impl_uuid_conversion!(22, Base62Alphabet);
+impl_uuid_conversion!(22, Base58Alphabet);
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::length_for_bytes;
+
+    const UUID: Uuid = uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+
+    #[test]
+    fn test_uuid_conversion() {
+        fn inner<A: Alphabet>(expected: &str)
+        where
+            Nanoid<22, A>: From<Uuid>,
+            Uuid: TryFrom<Nanoid<22, A>, Error = OverflowError>,
+        {
+            assert_eq!(length_for_bytes::<A>(16), 22);
+
+            let id = Nanoid::<22, A>::from(UUID);
+            assert_eq!(id.as_str(), expected);
+            assert_eq!(Uuid::try_from(id), Ok(UUID));
+
+            let zero = Nanoid::<22, A>::from_u64(0).unwrap();
+            assert_eq!(Nanoid::<22, A>::from(Uuid::nil()), zero);
+            assert_eq!(Uuid::try_from(zero), Ok(Uuid::nil()));
+            assert_eq!(
+                Uuid::try_from(Nanoid::<22, A>::from(Uuid::max())),
+                Ok(Uuid::max())
+            );
+        }
+
+        inner::<Base64UrlAlphabet>("Bn5VBEELFCb5JHu2gOX_DI");
+        inner::<Base62Alphabet>("DKDAYD03zdbLoHQ3h8FA4A");
+        inner::<Base58Alphabet>("NzGZnRYiLn9J56tvCtVZbL");
+    }
+
+    #[test]
+    fn test_uuid_overflow() {
+        assert_eq!(
+            Uuid::try_from(Nanoid::<22, Base64UrlAlphabet>::MAX),
+            Err(OverflowError)
+        );
+        assert_eq!(
+            Uuid::try_from(Nanoid::<22, Base62Alphabet>::MAX),
+            Err(OverflowError)
+        );
+        assert_eq!(
+            Uuid::try_from(Nanoid::<22, Base58Alphabet>::MAX),
+            Err(OverflowError)
+        );
+    }
+
+    #[test]
+    fn test_new_uuid_v4() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let id: Nanoid<22, Base62Alphabet> = Nanoid::new_uuid_v4_with(&mut rng);
+            let uuid = Uuid::try_from(id).unwrap();
+            assert_eq!(uuid.get_version_num(), 4);
+            assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+        }
+    }
+
+    #[test]
+    fn test_new_uuid_v7() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mut prev: Option<Nanoid<22, Base58Alphabet>> = None;
+        for timestamp in [0, 1, 1_700_000_000_000, (1 << 48) - 1] {
+            let id: Nanoid<22, Base58Alphabet> = Nanoid::new_uuid_v7_with(timestamp, &mut rng);
+            let uuid = Uuid::try_from(id).unwrap();
+            assert_eq!(uuid.get_version_num(), 7);
+            assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+
+            let (secs, nanos) = uuid.get_timestamp().unwrap().to_unix();
+            assert_eq!(secs * 1000 + u64::from(nanos) / 1_000_000, timestamp);
+
+            if let Some(prev) = prev {
+                assert!(prev.cmp_by_value(&id).is_lt());
+            }
+            prev = Some(id);
+        }
+    }
+}
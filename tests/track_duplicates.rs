@@ -0,0 +1,19 @@
+//! Duplicate tracking enables a process-wide flag, so it is tested in its own test binary.
+
+#![cfg(all(feature = "track-duplicates", debug_assertions))]
+
+use nid::{RuntimeAlphabet, RuntimeNanoid};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_runtime_alphabet_tracks_duplicates() {
+    nid::enable_duplicate_tracking();
+
+    // A single-symbol alphabet always generates the same Nano ID.
+    let alphabet = RuntimeAlphabet::new("a").unwrap();
+    let id: RuntimeNanoid<4> = alphabet.new_id();
+    assert_eq!(id.as_str(), "aaaa");
+
+    let result = std::panic::catch_unwind(|| alphabet.new_id::<4>());
+    assert!(result.is_err());
+}